@@ -0,0 +1,389 @@
+//! Contains the command-line interface used to build and inspect the courses.
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    build_courses,
+    earmaster::{
+        catalog,
+        edition::{self, Edition},
+        graph::{self, GraphFormat},
+        locale::{self, Locale, DEFAULT_LOCALE},
+        notation::Notation,
+        overlay, parallel, validation,
+        version::{self, EarMasterVersion},
+        EarMasterCourse,
+    },
+    import, output, select_courses, BuildOptions,
+};
+
+/// Generates Trane courses mirroring the activities in EarMaster.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options to select the catalogs from which the courses are generated.
+#[derive(Args, Debug)]
+pub struct CatalogArgs {
+    /// A directory with the catalogs to use instead of the ones bundled with the binary.
+    #[arg(long, value_name = "DIR")]
+    pub catalogs: Option<PathBuf>,
+
+    /// An overlay file that adds, changes, or hides lessons of the catalogs, such as the
+    /// customized exercises of a school. Can be repeated to apply several overlays in order.
+    #[arg(long, value_name = "FILE")]
+    pub overlay: Vec<PathBuf>,
+}
+
+impl CatalogArgs {
+    /// Loads the catalogs in the selected directory, or the bundled catalogs if no directory was
+    /// given, and applies the selected overlays.
+    fn load_courses(&self) -> Result<Vec<EarMasterCourse>> {
+        let courses = match &self.catalogs {
+            Some(directory) => catalog::load_catalog_directory(directory)?,
+            None => catalog::bundled_catalogs()?,
+        };
+        let overlays = self
+            .overlay
+            .iter()
+            .map(|path| overlay::load_overlay(path))
+            .collect::<Result<Vec<_>>>()?;
+        overlay::apply_overlays(&courses, &overlays)
+    }
+}
+
+/// Options to select the EarMaster editions for which the courses are built.
+#[derive(Args, Debug)]
+pub struct EditionArgs {
+    /// Only include the activities and units available in the edition with the given ID. Can be
+    /// repeated to include the content of several editions.
+    #[arg(long, value_name = "EDITION")]
+    pub edition: Vec<String>,
+
+    /// A file with the edition profiles to use instead of the ones bundled with the binary.
+    #[arg(long, value_name = "FILE")]
+    pub editions: Option<PathBuf>,
+}
+
+impl EditionArgs {
+    /// Loads the edition profiles from the selected file, or the bundled profiles if no file was
+    /// given.
+    fn load_profiles(&self) -> Result<Vec<Edition>> {
+        match &self.editions {
+            Some(path) => edition::load_editions(path),
+            None => edition::bundled_editions(),
+        }
+    }
+
+    /// Returns the selected editions.
+    fn load_editions(&self) -> Result<Vec<Edition>> {
+        edition::select_editions(&self.load_profiles()?, &self.edition)
+    }
+}
+
+/// Options to select the locale into which the courses are translated.
+#[derive(Args, Debug)]
+pub struct LocaleArgs {
+    /// The ID of the locale into which the names and instructions are translated, such as "de".
+    /// The IDs of the courses, lessons, and exercises are the same in every locale.
+    #[arg(long, value_name = "LOCALE", default_value = DEFAULT_LOCALE)]
+    pub locale: String,
+
+    /// A directory with the locale files to use instead of the ones bundled with the binary.
+    #[arg(long, value_name = "DIR")]
+    pub locales: Option<PathBuf>,
+}
+
+impl LocaleArgs {
+    /// Loads the locales in the selected directory, or the bundled locales if no directory was
+    /// given.
+    fn load_locales(&self) -> Result<Vec<Locale>> {
+        match &self.locales {
+            Some(directory) => locale::load_locale_directory(directory),
+            None => locale::bundled_locales(),
+        }
+    }
+
+    /// Returns the selected locale, or `None` for the default locale.
+    fn load_locale(&self) -> Result<Option<Locale>> {
+        locale::select_locale(&self.load_locales()?, &self.locale)
+    }
+}
+
+/// The subcommands supported by the command-line interface.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Builds the courses into the output directory.
+    Build {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+
+        #[command(flatten)]
+        edition_args: EditionArgs,
+
+        #[command(flatten)]
+        locale_args: LocaleArgs,
+
+        /// The directory in which to write the courses.
+        #[arg(long, value_name = "DIR")]
+        out: PathBuf,
+
+        /// The release of EarMaster whose lesson numbers and names are used.
+        #[arg(long, value_enum, value_name = "VERSION", default_value = "7.2")]
+        earmaster_version: EarMasterVersion,
+
+        /// The notation in which the scale degrees in the lesson names are written. Only the names
+        /// change, so the lesson IDs are the same in every notation.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "NOTATION",
+            default_value = "movable-do"
+        )]
+        notation: Notation,
+
+        /// Make every lesson depend on its parallel lessons in other activities, such as the same
+        /// melody in Melody Sight-Singing for a lesson of Melody Dictation, instead of making the
+        /// whole course depend on those activities.
+        #[arg(long)]
+        parallel_dependencies: bool,
+
+        /// Only build the course with the given ID or directory name. Can be repeated.
+        #[arg(long, value_name = "COURSE")]
+        only: Vec<String>,
+
+        /// Print the changes that would be made to the output directory without writing them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Validates the catalogs, the edition profiles, and the locales without writing anything to
+    /// disk.
+    Validate {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+
+        /// A file with the edition profiles to validate instead of the ones bundled with the
+        /// binary.
+        #[arg(long, value_name = "FILE")]
+        editions: Option<PathBuf>,
+
+        /// A directory with the locale files to validate instead of the ones bundled with the
+        /// binary.
+        #[arg(long, value_name = "DIR")]
+        locales: Option<PathBuf>,
+    },
+
+    /// Lists the courses along with their lesson counts and dependencies.
+    List {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+    },
+
+    /// Prints the dependency graph between the courses, or between the lessons of one course.
+    Graph {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+
+        /// The format in which to print the graph.
+        #[arg(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+
+        /// Print the graph of the lessons in the course with the given ID or directory name
+        /// instead of the graph of the courses.
+        #[arg(long, value_name = "COURSE")]
+        course: Option<String>,
+    },
+
+    /// Lists the number of parallel lessons between the courses and the parallel lessons whose
+    /// names have drifted apart.
+    Parallel {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+    },
+
+    /// Imports the results exported from EarMaster into the practice history of a Trane library.
+    ImportResults {
+        #[command(flatten)]
+        catalog_args: CatalogArgs,
+
+        /// The CSV file exported from EarMaster.
+        #[arg(value_name = "FILE")]
+        export: PathBuf,
+
+        /// The root of the Trane library containing the generated courses.
+        #[arg(long, value_name = "DIR")]
+        library: PathBuf,
+
+        /// The release of EarMaster from which the results were exported, used to match the
+        /// lesson numbers in the export.
+        #[arg(long, value_enum, value_name = "VERSION", default_value = "7.2")]
+        earmaster_version: EarMasterVersion,
+
+        #[command(flatten)]
+        locale_args: LocaleArgs,
+
+        /// Print how each result would be imported without writing to the library.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Removes the course directories generated by this tool from the output directory. Other
+    /// directories are left untouched.
+    Clean {
+        /// The directory from which to remove the generated courses.
+        #[arg(long, value_name = "DIR")]
+        out: PathBuf,
+    },
+}
+
+/// Prints each course with its lesson count and dependencies.
+fn list_courses(courses: &[EarMasterCourse]) {
+    for course in courses {
+        println!(
+            "{} - {} ({} lessons)",
+            course.id,
+            course.name,
+            course.lessons.len()
+        );
+        for dependency in &course.dependencies {
+            println!("    depends on {}", dependency);
+        }
+    }
+}
+
+/// Removes the generated courses from the output directory.
+fn clean(out: &Path) -> Result<()> {
+    let removed = output::clean(out)?;
+    for directory in &removed {
+        println!("Removed {}", directory.display());
+    }
+    println!("Removed {} generated courses", removed.len());
+    Ok(())
+}
+
+impl Cli {
+    /// Runs the selected subcommand.
+    pub fn run(&self) -> Result<()> {
+        match &self.command {
+            Command::Build {
+                catalog_args,
+                edition_args,
+                locale_args,
+                out,
+                earmaster_version,
+                notation,
+                parallel_dependencies,
+                only,
+                dry_run,
+            } => {
+                let options = BuildOptions {
+                    only: only.clone(),
+                    dry_run: *dry_run,
+                    editions: edition_args.load_editions()?,
+                    version: *earmaster_version,
+                    locale: locale_args.load_locale()?,
+                    notation: *notation,
+                    parallel_dependencies: *parallel_dependencies,
+                };
+                build_courses(&catalog_args.load_courses()?, &options, out)?;
+                Ok(())
+            }
+            Command::Validate {
+                catalog_args,
+                editions,
+                locales,
+            } => {
+                let courses = catalog_args.load_courses()?;
+                validation::validate_courses(&courses)?;
+                let editions = match editions {
+                    Some(path) => edition::load_editions(path)?,
+                    None => edition::bundled_editions()?,
+                };
+                let mut issues: Vec<String> = editions
+                    .iter()
+                    .flat_map(|edition| edition.issues(&courses))
+                    .collect();
+                issues.extend(edition::coverage_issues(&editions, &courses));
+                if !issues.is_empty() {
+                    bail!(
+                        "found {} issue(s) in the edition profiles:\n{}",
+                        issues.len(),
+                        issues.join("\n")
+                    );
+                }
+                let locales = match locales {
+                    Some(directory) => locale::load_locale_directory(directory)?,
+                    None => locale::bundled_locales()?,
+                };
+                let issues: Vec<String> = locales
+                    .iter()
+                    .flat_map(|locale| locale.issues(&courses))
+                    .collect();
+                if !issues.is_empty() {
+                    bail!(
+                        "found {} issue(s) in the locales:\n{}",
+                        issues.len(),
+                        issues.join("\n")
+                    );
+                }
+                println!(
+                    "Validated {} courses, {} editions, and {} locales",
+                    courses.len(),
+                    editions.len(),
+                    locales.len()
+                );
+                Ok(())
+            }
+            Command::List { catalog_args } => {
+                list_courses(&catalog_args.load_courses()?);
+                Ok(())
+            }
+            Command::Graph {
+                catalog_args,
+                format,
+                course,
+            } => {
+                let courses = catalog_args.load_courses()?;
+                let graph = match course {
+                    Some(course) => {
+                        let selected = select_courses(&courses, std::slice::from_ref(course))?;
+                        graph::lesson_graph(selected[0], *format)
+                    }
+                    None => graph::course_graph(&courses, *format),
+                };
+                print!("{}", graph);
+                Ok(())
+            }
+            Command::Parallel { catalog_args } => {
+                print!("{}", parallel::drift_report(&catalog_args.load_courses()?));
+                Ok(())
+            }
+            Command::ImportResults {
+                catalog_args,
+                export,
+                library,
+                earmaster_version,
+                locale_args,
+                dry_run,
+            } => {
+                let catalogs = catalog_args.load_courses()?;
+                let courses = version::courses_for_version(&catalogs, *earmaster_version);
+                let courses = locale::localize_courses(
+                    &catalogs,
+                    &courses,
+                    locale_args.load_locale()?.as_ref(),
+                )?;
+                import::import_results(&courses, export, library, *dry_run)?;
+                Ok(())
+            }
+            Command::Clean { out } => clean(out),
+        }
+    }
+}
@@ -1,22 +1,25 @@
-//! Module containing EarMaster courses.
-pub mod chord_identification;
-pub mod chord_inversions;
-pub mod chord_progressions;
-pub mod interval_comparison;
-pub mod interval_identification;
-pub mod interval_singing;
-pub mod melody_dictation;
-pub mod melody_sight_singing;
-pub mod melody_singback;
-pub mod rhythm_clapback;
-pub mod rhythm_dictation;
-pub mod rhythm_error_detection;
-pub mod rhythm_sight_reading;
-pub mod scale_identification;
-
-use std::collections::BTreeMap;
-
-use indoc::formatdoc;
+//! Module containing EarMaster courses. The courses are described by catalog files, one per
+//! EarMaster activity, which are loaded by the `catalog` module. Catalogs can also describe the
+//! activities of other apps with numbered lessons, which are built the same way.
+pub mod app;
+pub mod catalog;
+pub mod descriptor;
+pub mod edition;
+pub mod graph;
+pub mod locale;
+pub mod musictheory;
+pub mod notation;
+pub mod overlay;
+pub mod parallel;
+pub mod reference;
+pub mod rubric;
+pub mod validation;
+pub mod version;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
 use trane::{
     course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
     data::{
@@ -26,51 +29,220 @@ use trane::{
 };
 use ustr::Ustr;
 
-use crate::AUTHORS;
+use crate::{
+    earmaster::{
+        app::{CatalogApp, EarMaster, ExternalAppCourse, KnownApp},
+        descriptor::LessonDescriptor,
+        locale::{fill, Strings},
+        musictheory::Preset,
+        notation::Notation,
+        parallel::ParallelCourse,
+        reference::is_qualified,
+        rubric::ScoringRubric,
+        version::{EarMasterVersion, VersionChanges},
+    },
+    output::{GENERATED_METADATA_KEY, GENERATED_METADATA_VALUE},
+    AUTHORS,
+};
+
+/// Replaces every excluded dependency with the dependencies of the excluded item, recursively, so
+/// that the ordering implied by the excluded items is preserved. Duplicates are removed.
+pub fn rewrite_dependencies(
+    dependencies: &[Ustr],
+    included: &HashSet<Ustr>,
+    all_dependencies: &HashMap<Ustr, &[Ustr]>,
+) -> Vec<Ustr> {
+    let mut rewritten = vec![];
+    let mut visited = HashSet::new();
+    let mut pending: Vec<Ustr> = dependencies.iter().rev().copied().collect();
+    while let Some(dependency) = pending.pop() {
+        if !visited.insert(dependency) {
+            continue;
+        }
+        if included.contains(&dependency) {
+            rewritten.push(dependency);
+        } else if let Some(transitive) = all_dependencies.get(&dependency) {
+            pending.extend(transitive.iter().rev());
+        }
+    }
+    rewritten
+}
+
+/// Represents a unit in EarMaster, which groups the lessons whose IDs start with the unit's ID.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EarMasterUnit {
+    /// The ID of the unit, which is the part of the lesson IDs before the first dot.
+    pub id: String,
+
+    /// The title of the unit, as shown in EarMaster.
+    pub title: String,
+
+    /// An optional introduction to the unit, written in markdown.
+    pub introduction: Option<String>,
+}
+
+impl EarMasterUnit {
+    /// Returns the title of the unit with its ID, such as "Unit 1 - Seconds".
+    fn full_title(&self, strings: &Strings) -> String {
+        fill(&strings.unit, &[("id", &self.id), ("title", &self.title)])
+    }
+
+    /// Returns the contents of the instructions shown for every lesson in the unit.
+    fn instructions(&self, strings: &Strings) -> String {
+        let mut contents = format!("# {}\n", self.full_title(strings));
+        if let Some(introduction) = &self.introduction {
+            contents.push('\n');
+            contents.push_str(introduction.trim_end());
+            contents.push('\n');
+        }
+        contents
+    }
+}
 
 /// Represents a lesson in EarMaster. Each lesson contains only one exercise corresponding to the
 /// EarMaster unit with the given ID.
-struct EarMasterLesson {
+#[derive(Clone, Debug, Deserialize)]
+pub struct EarMasterLesson {
     /// A shorthand for the ID of the lesson "1.1".
-    id: Ustr,
+    pub id: Ustr,
+
+    /// The template of the name of the lesson, in which the placeholders `{degrees}`, `{start}`,
+    /// and `{targets}` are replaced with the scale degrees in the descriptor, and `{bars}` with the
+    /// number of bars. If omitted from the catalog, the name is generated from the lesson's
+    /// descriptor.
+    #[serde(rename = "name", default)]
+    pub name_template: Option<String>,
+
+    /// The full name of the lesson, rendered in the notation selected for the course.
+    #[serde(skip)]
+    pub name: String,
 
-    /// The full name of the lesson,
-    name: String,
+    /// The dependencies of this lesson, also written in the short ID format. Lessons in other
+    /// courses are qualified with the directory name or ID of their course, as in
+    /// `rhythm_dictation::1.8`.
+    #[serde(default)]
+    pub dependencies: Vec<Ustr>,
 
-    /// The dependencies of this lesson, also written in the short ID format.
-    dependencies: Vec<Ustr>,
+    /// Whether the lesson reviews the lessons it depends on, such as the test at the end of a unit.
+    /// Summary lessons supersede their direct dependencies unless `superseded` is given.
+    #[serde(default)]
+    pub summary: bool,
+
+    /// The lessons superseded by this lesson, written in the short ID format. Once the student
+    /// masters this lesson, Trane stops scheduling the superseded lessons.
+    pub superseded: Option<Vec<Ustr>>,
+
+    /// The exercise and settings to use on musictheory.net, for the lessons of that app.
+    pub preset: Option<Preset>,
+
+    /// The description of the contents of the lesson, which is emitted as the lesson's metadata.
+    #[serde(flatten)]
+    pub descriptor: LessonDescriptor,
+
+    /// The number of the lesson in the selected release of EarMaster, if it differs from its ID.
+    #[serde(skip)]
+    pub number: Option<String>,
 }
 
 impl EarMasterLesson {
-    fn new(id: &str, name: &str, dependencies: Vec<&str>) -> Self {
-        Self {
-            id: Ustr::from(id),
-            name: name.to_string(),
-            dependencies: dependencies.iter().map(|&id| Ustr::from(id)).collect(),
+    /// Returns the ID of the unit to which the lesson belongs, which is the part of the lesson ID
+    /// before the first dot.
+    pub fn unit_id(&self) -> &str {
+        self.id.split('.').next().unwrap_or_default()
+    }
+
+    /// Returns the number of the lesson in the selected release of EarMaster.
+    pub fn number(&self) -> &str {
+        self.number.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Replaces the template of the name of the lesson and renders it in the given notation, with
+    /// the vocabulary in the given strings.
+    pub fn rename(&mut self, template: &str, notation: Notation, strings: &Strings) {
+        self.name_template = Some(template.to_string());
+        self.name = self.descriptor.render_name(template, notation, strings);
+    }
+
+    /// Returns the full ID of the lesson in the course with the given ID.
+    pub fn full_id(&self, course_id: &Ustr) -> Ustr {
+        Ustr::from(&format!("{}::{}", course_id, self.id))
+    }
+
+    /// Returns the ID of the only exercise in the lesson, in the course with the given ID.
+    pub fn exercise_id(&self, course_id: &Ustr) -> Ustr {
+        Ustr::from(&format!("{}::exercise", self.full_id(course_id)))
+    }
+
+    /// Returns the lessons superseded by this lesson, in the short ID format. Summary lessons do not
+    /// supersede the lessons of other courses on which they depend.
+    pub fn superseded_lessons(&self) -> Vec<Ustr> {
+        match &self.superseded {
+            Some(superseded) => superseded.clone(),
+            None if self.summary => self
+                .dependencies
+                .iter()
+                .filter(|dependency| !is_qualified(dependency))
+                .copied()
+                .collect(),
+            None => vec![],
         }
     }
 
-    /// Generates a `LessonBuilder` based on this object.
-    fn lesson_builder(&self, course_id: &Ustr, course_name: &str) -> LessonBuilder {
-        let lesson_id = format!("{}::{}", course_id, self.id);
-        let exercise_id = format!("{}::exercise", lesson_id);
+    /// Generates a `LessonBuilder` based on this object. The front of the exercise asks to work on
+    /// the lesson in the given app, followed by the preset of the lesson if it has one, the back
+    /// contains the given scoring rubric, and the lesson instructions introduce the given unit. The
+    /// text is written with the given templates.
+    fn lesson_builder(
+        &self,
+        course_id: &Ustr,
+        course_name: &str,
+        rubric: &str,
+        unit: Option<&EarMasterUnit>,
+        app: &dyn ExternalAppCourse,
+        strings: &Strings,
+    ) -> LessonBuilder {
+        let lesson_id = self.full_id(course_id);
+        let exercise_id = self.exercise_id(course_id);
+        let full_id = |id: &Ustr| Ustr::from(&format!("{}::{}", course_id, id));
         let dependencies: Vec<Ustr> = self
             .dependencies
             .iter()
-            .map(|id| Ustr::from(&format!("{}::{}", course_id, id)))
+            .map(|id| if is_qualified(id) { *id } else { full_id(id) })
             .collect();
+        let superseded: Vec<Ustr> = self.superseded_lessons().iter().map(full_id).collect();
+
+        let metadata = Some(self.descriptor.metadata()).filter(|metadata| !metadata.is_empty());
+        let (instructions, asset_builders) = match unit {
+            Some(unit) => (
+                Some(BasicAsset::MarkdownAsset {
+                    path: "instructions.md".to_string(),
+                }),
+                vec![AssetBuilder {
+                    file_name: "instructions.md".to_string(),
+                    contents: unit.instructions(strings),
+                }],
+            ),
+            None => (None, vec![]),
+        };
+
+        let mut front = app.exercise_front(strings, course_name, &self.name, self.number());
+        if let Some(preset) = &self.preset {
+            front.push_str(&preset.contents(strings));
+        }
 
-        let lesson_id_clone = lesson_id.clone();
         let name_clone = self.name.clone();
         LessonBuilder {
             directory_name: format!("lesson_{}", self.id),
-            asset_builders: vec![],
+            asset_builders,
             manifest_closure: Box::new(move |m| {
                 #[allow(clippy::redundant_clone)]
                 m.clone()
-                    .id(lesson_id_clone.clone())
+                    .id(lesson_id)
                     .name(name_clone.clone())
                     .dependencies(dependencies.clone())
+                    .superseded(superseded.clone())
+                    .metadata(metadata.clone())
+                    .lesson_instructions(instructions.clone())
                     .clone()
             }),
             exercise_manifest_template: ExerciseManifestBuilder::default()
@@ -81,66 +253,245 @@ impl EarMasterLesson {
                 .exercise_type(ExerciseType::Procedural)
                 .exercise_asset(ExerciseAsset::FlashcardAsset {
                     front_path: "front.md".to_string(),
-                    back_path: None,
+                    back_path: Some("back.md".to_string()),
                 })
                 .clone(),
             exercise_builders: vec![ExerciseBuilder {
                 directory_name: "exercise".to_string(),
                 manifest_closure: Box::new(|m| m),
-                asset_builders: vec![AssetBuilder {
-                    file_name: "front.md".to_string(),
-                    contents: formatdoc! {"
-                            Work on the following exercise in EarMaster:
-                            - Activity: {}
-                            - Exercise Name: {}
-                            - Exercise Number: {}
-                        ", course_name, self.name, self.id},
-                }],
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: front,
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: rubric.to_string(),
+                    },
+                ],
             }],
         }
     }
 }
-/// Represents a course based on one of the activities in EarMaster.
-struct EarMasterCourse {
+
+/// Represents a course based on one of the activities in EarMaster, or in the app described in
+/// its catalog.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EarMasterCourse {
     /// The full ID for this course.
-    id: Ustr,
+    pub id: Ustr,
 
     /// The name of the course.
-    name: String,
+    pub name: String,
 
     /// The dependencies of this course.
-    dependencies: Vec<Ustr>,
+    #[serde(default)]
+    pub dependencies: Vec<Ustr>,
 
     /// The name of the directory under which the course is stored.
-    directory_name: String,
+    pub directory_name: String,
+
+    /// Optional metadata. The metadata key of the app and the key value pair
+    /// ("generated_by", "trane-earmaster") are added to every course.
+    pub metadata: Option<BTreeMap<String, Vec<String>>>,
+
+    /// The app whose lessons the course mirrors, if it is not EarMaster.
+    pub app: Option<CatalogApp>,
+
+    /// The rubric used to score the exercises in the course, which is written to the back of
+    /// every exercise.
+    #[serde(default)]
+    pub rubric: ScoringRubric,
+
+    /// Optional material explaining the theory behind the course, written in markdown.
+    pub material: Option<String>,
+
+    /// The courses whose lessons parallel the lessons of this course, such as the activity that
+    /// practices the same melodies before they are dictated.
+    #[serde(default)]
+    pub parallel: Vec<ParallelCourse>,
 
-    /// Optional metadata. The key value pair ("earmaster", "true") is added to every course.
-    metadata: Option<BTreeMap<String, Vec<String>>>,
+    /// The units in the course. Units are optional, and lessons whose unit is not listed have no
+    /// instructions.
+    #[serde(default)]
+    pub units: Vec<EarMasterUnit>,
 
     /// The lessons in the course.
-    lessons: Vec<EarMasterLesson>,
+    pub lessons: Vec<EarMasterLesson>,
+
+    /// The changes to the lessons in earlier releases of EarMaster, indexed by release.
+    #[serde(default)]
+    pub versions: BTreeMap<EarMasterVersion, VersionChanges>,
+
+    /// The release of EarMaster for which the course is built.
+    #[serde(skip)]
+    pub version: EarMasterVersion,
+
+    /// The templates of the generated text, which are translated when building for a locale.
+    #[serde(skip)]
+    pub strings: Strings,
+
+    /// The notation in which the scale degrees in the lesson names are written.
+    #[serde(skip)]
+    pub notation: Notation,
+
+    /// The name of the catalog file from which the course was loaded. Used to give context to
+    /// errors.
+    #[serde(skip)]
+    pub source: String,
+
+    /// The lessons hidden by an overlay, as they were before being hidden. Locales can still
+    /// translate them, since they are only hidden in some libraries, and the dependencies of other
+    /// courses on them are replaced with their dependencies.
+    #[serde(skip)]
+    pub hidden_lessons: Vec<EarMasterLesson>,
 }
 
 impl EarMasterCourse {
-    fn course_builder(&self) -> CourseBuilder {
-        let mut metadata: BTreeMap<String, Vec<String>> =
-            BTreeMap::from([("earmaster".to_string(), vec!["true".to_string()])]);
+    /// Renders the names of the lessons in the course's notation and with the vocabulary in its
+    /// strings. Lessons without a name template use the name generated from their descriptors.
+    pub fn render_lesson_names(&mut self) -> Result<()> {
+        for lesson in &mut self.lessons {
+            lesson.name = match &lesson.name_template {
+                Some(template) => {
+                    lesson
+                        .descriptor
+                        .render_name(template, self.notation, &self.strings)
+                }
+                None => lesson
+                    .descriptor
+                    .generated_name(self.notation, &self.strings)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "{}: lesson {} has no name and one cannot be generated from its \
+                            descriptor",
+                            self.source,
+                            lesson.id
+                        )
+                    })?,
+            };
+        }
+        Ok(())
+    }
+
+    /// Removes the lessons for which `keep` returns false. The dependencies on the removed lessons
+    /// are replaced with their own dependencies, and units without any lessons left are removed.
+    /// The dependencies on the lessons of other courses are kept, since they are resolved once
+    /// the lessons of every course are known.
+    pub fn retain_lessons(&mut self, keep: impl Fn(&EarMasterLesson) -> bool) {
+        let kept_lessons: HashSet<Ustr> = self
+            .lessons
+            .iter()
+            .filter(|lesson| keep(lesson))
+            .map(|lesson| lesson.id)
+            .collect();
+        let all_dependencies: HashMap<Ustr, &[Ustr]> = self
+            .lessons
+            .iter()
+            .map(|lesson| (lesson.id, lesson.dependencies.as_slice()))
+            .collect();
+        let included: HashSet<Ustr> = self
+            .lessons
+            .iter()
+            .flat_map(|lesson| lesson.dependencies.iter().copied().filter(is_qualified))
+            .chain(kept_lessons.iter().copied())
+            .collect();
+        let lessons = self
+            .lessons
+            .iter()
+            .filter(|lesson| kept_lessons.contains(&lesson.id))
+            .map(|lesson| {
+                let mut lesson = lesson.clone();
+                let superseded = lesson.superseded_lessons();
+                lesson.superseded = Some(
+                    superseded
+                        .into_iter()
+                        .filter(|id| kept_lessons.contains(id))
+                        .collect(),
+                );
+                lesson.dependencies =
+                    rewrite_dependencies(&lesson.dependencies, &included, &all_dependencies);
+                lesson
+            })
+            .collect();
+        self.lessons = lessons;
+        let lessons = &self.lessons;
+        self.units
+            .retain(|unit| lessons.iter().any(|lesson| lesson.unit_id() == unit.id));
+    }
+
+    /// Returns the app whose lessons the course mirrors.
+    pub fn app(&self) -> Box<dyn ExternalAppCourse> {
+        match &self.app {
+            Some(app) => app.external_app(),
+            None => Box::new(EarMaster {
+                version: self.version,
+            }),
+        }
+    }
+
+    /// Returns whether the course mirrors the exercises on musictheory.net.
+    pub fn is_musictheory(&self) -> bool {
+        matches!(self.app, Some(CatalogApp::Known(KnownApp::MusicTheoryNet)))
+    }
+
+    /// Returns the unit to which the given lesson belongs, if it is listed in the course.
+    pub fn unit(&self, lesson: &EarMasterLesson) -> Option<&EarMasterUnit> {
+        self.units.iter().find(|unit| unit.id == lesson.unit_id())
+    }
+
+    /// Generates a `CourseBuilder` based on this object.
+    pub fn course_builder(&self) -> CourseBuilder {
+        let app = self.app();
+        let mut metadata: BTreeMap<String, Vec<String>> = BTreeMap::from([
+            (
+                GENERATED_METADATA_KEY.to_string(),
+                vec![GENERATED_METADATA_VALUE.to_string()],
+            ),
+            (app.metadata_key().to_string(), vec![app.metadata_value()]),
+        ]);
         if let Some(input_metadata) = self.metadata.clone() {
             metadata.extend(input_metadata);
         }
 
+        let strings = &self.strings;
+        let rubric = self.rubric.back_contents(strings, app.app_name());
         let lesson_builders = self
             .lessons
             .iter()
-            .map(|lesson| lesson.lesson_builder(&self.id, &self.name))
+            .map(|lesson| {
+                let unit = self.unit(lesson);
+                lesson.lesson_builder(&self.id, &self.name, &rubric, unit, app.as_ref(), strings)
+            })
             .collect();
 
+        let mut instructions = app.course_instructions(strings, &self.name);
+        if !self.units.is_empty() {
+            instructions.push_str(&format!("\n{}\n\n", strings.course_units));
+            for unit in &self.units {
+                instructions.push_str(&format!("- {}\n", unit.full_title(strings)));
+            }
+        }
+        let mut asset_builders = vec![AssetBuilder {
+            file_name: "instructions.md".to_string(),
+            contents: instructions,
+        }];
+        if let Some(material) = &self.material {
+            asset_builders.push(AssetBuilder {
+                file_name: "material.md".to_string(),
+                contents: format!("{}\n", material.trim_end()),
+            });
+        }
+
         CourseBuilder {
             directory_name: self.directory_name.clone(),
             course_manifest: CourseManifest {
                 id: self.id,
                 name: self.name.clone(),
-                description: Some(format!("Practice EarMaster activity {}", self.name)),
+                description: Some(fill(
+                    &strings.course_description,
+                    &[("app", app.app_name()), ("activity", &self.name)],
+                )),
                 dependencies: self.dependencies.clone(),
                 superseded: vec![],
                 authors: Some(vec![AUTHORS.to_string()]),
@@ -148,20 +499,14 @@ impl EarMasterCourse {
                 course_instructions: Some(BasicAsset::MarkdownAsset {
                     path: "instructions.md".to_string(),
                 }),
-                course_material: None,
+                course_material: self.material.as_ref().map(|_| BasicAsset::MarkdownAsset {
+                    path: "material.md".to_string(),
+                }),
                 generator_config: None,
             },
             lesson_manifest_template: LessonManifestBuilder::default().course_id(self.id).clone(),
             lesson_builders,
-            asset_builders: vec![AssetBuilder {
-                file_name: "instructions.md".to_string(),
-                contents: formatdoc! {"
-                        This course contains the exercises from the {} 
-                        activity in EarMaster 7.2. The exercises are referenced by the same
-                        numbers as in EarMaster. If you do not have a copy of EarMaster, you
-                        can add this course to the blacklist.
-                    ", self.name},
-            }],
+            asset_builders,
         }
     }
 }
@@ -0,0 +1,261 @@
+//! Contains the external apps whose numbered lessons are mirrored as Trane courses. Every lesson
+//! becomes a procedural flashcard asking the student to work on the exercise in the app, so the
+//! courses of different apps only differ in the name and release of the app, the templates of the
+//! exercise cards and course instructions, and the metadata marking the courses. EarMaster and
+//! musictheory.net are built in, and other apps are described with the `app` table of their
+//! catalogs.
+//!
+//! The apps live in the `earmaster` module because EarMaster was the first app to be supported.
+//! Despite their names, `EarMasterCourse` and `EarMasterLesson` describe the courses and lessons of
+//! any app, and only the implementation of `ExternalAppCourse` used to build them is specific to
+//! EarMaster.
+use serde::Deserialize;
+
+use super::{
+    locale::{fill, Strings},
+    musictheory::MusicTheoryNet,
+    version::EarMasterVersion,
+};
+
+/// The value of the metadata key of an app if none is given.
+fn default_metadata_value() -> String {
+    "true".to_string()
+}
+
+/// The parts of a course that depend on the external app whose lessons it mirrors.
+pub trait ExternalAppCourse {
+    /// Returns the name of the app, such as "EarMaster".
+    fn app_name(&self) -> &str;
+
+    /// Returns the release of the app whose lessons are mirrored, if the app has releases.
+    fn app_version(&self) -> Option<String>;
+
+    /// Returns the template of the front of the exercise cards. Placeholders: `app`, `activity`,
+    /// `name`, and `number`.
+    fn exercise_template<'a>(&'a self, strings: &'a Strings) -> &'a str;
+
+    /// Returns the template of the course instructions. Placeholders: `app`, `activity`, and
+    /// `version`.
+    fn instructions_template<'a>(&'a self, strings: &'a Strings) -> &'a str;
+
+    /// Returns the metadata key added to every course of the app.
+    fn metadata_key(&self) -> &str;
+
+    /// Returns the value of the metadata key added to every course of the app.
+    fn metadata_value(&self) -> String {
+        default_metadata_value()
+    }
+
+    /// Returns the front of the card of the exercise with the given name and number, in the
+    /// activity with the given name.
+    fn exercise_front(
+        &self,
+        strings: &Strings,
+        activity: &str,
+        name: &str,
+        number: &str,
+    ) -> String {
+        fill(
+            self.exercise_template(strings),
+            &[
+                ("app", self.app_name()),
+                ("activity", activity),
+                ("name", name),
+                ("number", number),
+            ],
+        )
+    }
+
+    /// Returns the instructions of the course for the activity with the given name.
+    fn course_instructions(&self, strings: &Strings, activity: &str) -> String {
+        let version = self.app_version().unwrap_or_default();
+        fill(
+            self.instructions_template(strings),
+            &[
+                ("app", self.app_name()),
+                ("activity", activity),
+                ("version", &version),
+            ],
+        )
+    }
+}
+
+/// EarMaster, whose lesson numbers and names depend on the selected release.
+pub struct EarMaster {
+    /// The release of EarMaster for which the course is built.
+    pub version: EarMasterVersion,
+}
+
+impl ExternalAppCourse for EarMaster {
+    fn app_name(&self) -> &str {
+        "EarMaster"
+    }
+
+    fn app_version(&self) -> Option<String> {
+        Some(self.version.to_string())
+    }
+
+    fn exercise_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        &strings.exercise_front
+    }
+
+    fn instructions_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        &strings.course_instructions
+    }
+
+    fn metadata_key(&self) -> &str {
+        "earmaster"
+    }
+}
+
+/// An app described in a catalog, which allows adding courses for other apps without any code.
+/// The templates default to the ones used for EarMaster, with the name of the app filled in.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppProfile {
+    /// The name of the app, as shown in the instructions and exercise cards.
+    pub name: String,
+
+    /// The release of the app whose lessons are mirrored, if the app has releases.
+    pub version: Option<String>,
+
+    /// The metadata key added to every course of the app.
+    pub metadata_key: String,
+
+    /// The value of the metadata key. Defaults to "true".
+    #[serde(default = "default_metadata_value")]
+    pub metadata_value: String,
+
+    /// The template of the front of the exercise cards, if different from the default.
+    pub exercise_front: Option<String>,
+
+    /// The template of the course instructions, if different from the default.
+    pub course_instructions: Option<String>,
+}
+
+impl AppProfile {
+    /// Returns the problems with the description of the app.
+    pub fn issues(&self, strings: &Strings) -> Vec<String> {
+        let mut issues = vec![];
+        if self.name.is_empty() {
+            issues.push("the app has no name".to_string());
+        }
+        if self.metadata_key.is_empty() {
+            issues.push("the app has no metadata key".to_string());
+        }
+        if self.version.is_none() && self.instructions_template(strings).contains("{version}") {
+            issues.push(
+                "the course instructions of the app use {version} but the app has no version"
+                    .to_string(),
+            );
+        }
+        issues
+    }
+}
+
+impl ExternalAppCourse for AppProfile {
+    fn app_name(&self) -> &str {
+        &self.name
+    }
+
+    fn app_version(&self) -> Option<String> {
+        self.version.clone()
+    }
+
+    fn exercise_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        self.exercise_front
+            .as_deref()
+            .unwrap_or(&strings.exercise_front)
+    }
+
+    fn instructions_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        self.course_instructions
+            .as_deref()
+            .unwrap_or(&strings.course_instructions)
+    }
+
+    fn metadata_key(&self) -> &str {
+        &self.metadata_key
+    }
+
+    fn metadata_value(&self) -> String {
+        self.metadata_value.clone()
+    }
+}
+
+/// The apps other than EarMaster with built-in support, referenced by name in the catalogs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum KnownApp {
+    /// The free exercises on musictheory.net.
+    #[serde(rename = "musictheory.net")]
+    MusicTheoryNet,
+}
+
+/// The app whose lessons a course mirrors, as written in its catalog.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CatalogApp {
+    /// An app with built-in support, such as `app = "musictheory.net"`.
+    Known(KnownApp),
+
+    /// An app described in the `app` table of the catalog.
+    Custom(AppProfile),
+}
+
+impl CatalogApp {
+    /// Returns the implementation of the app.
+    pub fn external_app(&self) -> Box<dyn ExternalAppCourse> {
+        match self {
+            CatalogApp::Known(KnownApp::MusicTheoryNet) => Box::new(MusicTheoryNet),
+            CatalogApp::Custom(profile) => Box::new(profile.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AppProfile, EarMaster, ExternalAppCourse};
+    use crate::earmaster::{locale::Strings, version::EarMasterVersion};
+
+    /// Verifies that the templates are filled with the name and release of the app.
+    #[test]
+    fn fills_app_templates() {
+        let strings = Strings::default();
+        let earmaster = EarMaster {
+            version: EarMasterVersion::V7_2,
+        };
+        assert_eq!(
+            earmaster.exercise_front(&strings, "Interval Singing", "Do - Re", "1.1"),
+            "Work on the following exercise in EarMaster:\n\
+            - Activity: Interval Singing\n\
+            - Exercise Name: Do - Re\n\
+            - Exercise Number: 1.1\n"
+        );
+        assert!(earmaster
+            .course_instructions(&strings, "Interval Singing")
+            .contains("activity in EarMaster 7.2."));
+
+        let profile: AppProfile = toml::from_str(
+            r#"
+                name = "Other App"
+                metadata_key = "app"
+                metadata_value = "other"
+                course_instructions = "Use {app} to practice {activity}."
+            "#,
+        )
+        .unwrap();
+        assert!(profile.issues(&strings).is_empty());
+        assert_eq!(
+            profile.course_instructions(&strings, "Scales"),
+            "Use Other App to practice Scales."
+        );
+        assert!(profile
+            .exercise_front(&strings, "Scales", "Major", "1")
+            .starts_with("Work on the following exercise in Other App:"));
+
+        let unversioned = AppProfile {
+            course_instructions: None,
+            ..profile
+        };
+        assert_eq!(unversioned.issues(&strings).len(), 1);
+    }
+}
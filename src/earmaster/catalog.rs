@@ -0,0 +1,241 @@
+//! Loads EarMaster courses from catalog files. Each catalog describes a single EarMaster activity
+//! and can be written in either TOML or JSON, with the format selected by the file extension. The
+//! default catalogs are bundled into the binary so that the courses can be built without any
+//! external files.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use super::EarMasterCourse;
+
+/// The catalogs bundled with the binary, as tuples of file name and contents. The EarMaster
+/// catalogs are followed by the catalogs of the other apps, whose names include their directory.
+const BUNDLED_CATALOGS: &[(&str, &str)] = &[
+    (
+        "chord_identification.toml",
+        include_str!("../../catalogs/earmaster/chord_identification.toml"),
+    ),
+    (
+        "chord_inversions.toml",
+        include_str!("../../catalogs/earmaster/chord_inversions.toml"),
+    ),
+    (
+        "chord_progressions.toml",
+        include_str!("../../catalogs/earmaster/chord_progressions.toml"),
+    ),
+    (
+        "chord_singing.toml",
+        include_str!("../../catalogs/earmaster/chord_singing.toml"),
+    ),
+    (
+        "harmonic_dictation.toml",
+        include_str!("../../catalogs/earmaster/harmonic_dictation.toml"),
+    ),
+    (
+        "harmony_singing.toml",
+        include_str!("../../catalogs/earmaster/harmony_singing.toml"),
+    ),
+    (
+        "interval_comparison.toml",
+        include_str!("../../catalogs/earmaster/interval_comparison.toml"),
+    ),
+    (
+        "interval_identification.toml",
+        include_str!("../../catalogs/earmaster/interval_identification.toml"),
+    ),
+    (
+        "interval_singing.toml",
+        include_str!("../../catalogs/earmaster/interval_singing.toml"),
+    ),
+    (
+        "jazz_chord_identification.toml",
+        include_str!("../../catalogs/earmaster/jazz_chord_identification.toml"),
+    ),
+    (
+        "jazz_song_singing.toml",
+        include_str!("../../catalogs/earmaster/jazz_song_singing.toml"),
+    ),
+    (
+        "melody_dictation.toml",
+        include_str!("../../catalogs/earmaster/melody_dictation.toml"),
+    ),
+    (
+        "melody_sight_singing.toml",
+        include_str!("../../catalogs/earmaster/melody_sight_singing.toml"),
+    ),
+    (
+        "melody_singback.toml",
+        include_str!("../../catalogs/earmaster/melody_singback.toml"),
+    ),
+    (
+        "rhythm_clapback.toml",
+        include_str!("../../catalogs/earmaster/rhythm_clapback.toml"),
+    ),
+    (
+        "rhythm_dictation.toml",
+        include_str!("../../catalogs/earmaster/rhythm_dictation.toml"),
+    ),
+    (
+        "rhythm_error_detection.toml",
+        include_str!("../../catalogs/earmaster/rhythm_error_detection.toml"),
+    ),
+    (
+        "rhythm_sight_reading.toml",
+        include_str!("../../catalogs/earmaster/rhythm_sight_reading.toml"),
+    ),
+    (
+        "scale_identification.toml",
+        include_str!("../../catalogs/earmaster/scale_identification.toml"),
+    ),
+    (
+        "swing_rhythm.toml",
+        include_str!("../../catalogs/earmaster/swing_rhythm.toml"),
+    ),
+    (
+        "musictheory/chord_ear_trainer.toml",
+        include_str!("../../catalogs/musictheory/chord_ear_trainer.toml"),
+    ),
+    (
+        "musictheory/chords.toml",
+        include_str!("../../catalogs/musictheory/chords.toml"),
+    ),
+    (
+        "musictheory/interval_ear_trainer.toml",
+        include_str!("../../catalogs/musictheory/interval_ear_trainer.toml"),
+    ),
+    (
+        "musictheory/intervals.toml",
+        include_str!("../../catalogs/musictheory/intervals.toml"),
+    ),
+    (
+        "musictheory/notes.toml",
+        include_str!("../../catalogs/musictheory/notes.toml"),
+    ),
+    (
+        "musictheory/scale_ear_trainer.toml",
+        include_str!("../../catalogs/musictheory/scale_ear_trainer.toml"),
+    ),
+];
+
+/// Returns whether the file has the extension of a supported catalog format.
+fn is_catalog_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("toml") | Some("json")
+    )
+}
+
+/// Parses the contents of a catalog. The file name is used to select the format and to give
+/// context to any errors.
+pub fn parse_catalog(file_name: &str, contents: &str) -> Result<EarMasterCourse> {
+    let mut course: EarMasterCourse = match Path::new(file_name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("toml") => toml::from_str(contents)
+            .with_context(|| format!("failed to parse TOML catalog {}", file_name))?,
+        Some("json") => serde_json::from_str(contents)
+            .with_context(|| format!("failed to parse JSON catalog {}", file_name))?,
+        _ => bail!("catalog {} is not a TOML or JSON file", file_name),
+    };
+    course.source = file_name.to_string();
+    course.render_lesson_names()?;
+    Ok(course)
+}
+
+/// Reads and parses the catalog at the given path.
+pub fn load_catalog(path: &Path) -> Result<EarMasterCourse> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read catalog {}", path.display()))?;
+    parse_catalog(&path.display().to_string(), &contents)
+}
+
+/// Loads every catalog in the given directory. The catalogs are returned in the order of their
+/// file names so that the courses are always built in the same order.
+pub fn load_catalog_directory(directory: &Path) -> Result<Vec<EarMasterCourse>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)
+        .with_context(|| format!("failed to read catalog directory {}", directory.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    paths.retain(|path| path.is_file() && is_catalog_file(path));
+    paths.sort();
+
+    paths.iter().map(|path| load_catalog(path)).collect()
+}
+
+/// Returns the courses described by the catalogs bundled with the binary.
+pub fn bundled_catalogs() -> Result<Vec<EarMasterCourse>> {
+    BUNDLED_CATALOGS
+        .iter()
+        .map(|(file_name, contents)| parse_catalog(file_name, contents))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use trane::course_builder::music::MusicMetadata;
+
+    use super::{bundled_catalogs, load_catalog_directory};
+    use crate::earmaster::{rubric::ActivityKind, validation::find_issues};
+
+    /// Verifies that the catalogs on disk are the same ones bundled with the binary.
+    #[test]
+    fn bundled_catalogs_match_directory() -> anyhow::Result<()> {
+        let catalogs = Path::new(env!("CARGO_MANIFEST_DIR")).join("catalogs");
+        let mut from_directory = load_catalog_directory(&catalogs.join("earmaster"))?;
+        from_directory.extend(load_catalog_directory(&catalogs.join("musictheory"))?);
+        let bundled = bundled_catalogs()?;
+        assert_eq!(from_directory.len(), bundled.len());
+        for (disk, bundled) in from_directory.iter().zip(bundled.iter()) {
+            assert_eq!(disk.id, bundled.id);
+            assert_eq!(disk.lessons.len(), bundled.lessons.len());
+        }
+        Ok(())
+    }
+
+    /// Verifies that the dependencies of every lesson in the Harmonic Dictation course resolve,
+    /// both to the other lessons in the course and to the courses it builds on.
+    #[test]
+    fn harmonic_dictation_dependencies_resolve() -> anyhow::Result<()> {
+        let courses = bundled_catalogs()?;
+        let course = courses
+            .iter()
+            .find(|course| course.directory_name == "harmonic_dictation")
+            .unwrap();
+        for dependency in &course.dependencies {
+            assert!(courses.iter().any(|other| other.id == *dependency));
+        }
+        for lesson in &course.lessons {
+            for dependency in &lesson.dependencies {
+                assert!(course.lessons.iter().any(|other| other.id == *dependency));
+            }
+        }
+        assert!(find_issues(&courses).is_empty());
+        Ok(())
+    }
+
+    /// Verifies that every singing course can be selected with the singing skill metadata.
+    #[test]
+    fn singing_courses_have_skill_metadata() -> anyhow::Result<()> {
+        for course in bundled_catalogs()? {
+            if course.rubric.activity != ActivityKind::Singing {
+                continue;
+            }
+            let skills = course
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(&MusicMetadata::MusicalSkill.to_string()));
+            assert!(
+                skills.is_some_and(|skills| skills.contains(&"singing".to_string())),
+                "{} is missing the singing skill",
+                course.id
+            );
+        }
+        Ok(())
+    }
+}
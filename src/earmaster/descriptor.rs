@@ -0,0 +1,952 @@
+//! Contains the typed descriptors of the contents of an EarMaster lesson. The descriptors are
+//! emitted as lesson metadata so that Trane filters can select lessons across all the activities,
+//! for example only the lessons with harmonic intervals or only the lessons in 6/8.
+use std::{collections::BTreeMap, fmt};
+
+use serde::Deserialize;
+use trane::course_builder::music::MusicMetadata;
+
+use super::{
+    locale::{fill, Strings},
+    notation::Notation,
+};
+
+/// The direction in which the notes of an interval or chord are played.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// The notes are played one after the other from the lowest to the highest.
+    Ascending,
+
+    /// The notes are played one after the other from the highest to the lowest.
+    Descending,
+
+    /// The notes are played at the same time.
+    Harmonic,
+}
+
+impl Direction {
+    /// Returns the short form of the direction used in the catalogs and the metadata.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Direction::Ascending => "ascending",
+            Direction::Descending => "descending",
+            Direction::Harmonic => "harmonic",
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.short_name())
+    }
+}
+
+/// An interval between two notes, up to two octaves. The intervals are declared in the order of
+/// their size in semitones. Only the intervals used in EarMaster are included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum Interval {
+    /// A unison, with both notes at the same pitch.
+    #[serde(rename = "P1")]
+    Unison,
+
+    /// A minor second, one semitone.
+    #[serde(rename = "m2")]
+    MinorSecond,
+
+    /// A major second, two semitones.
+    #[serde(rename = "M2")]
+    MajorSecond,
+
+    /// A minor third, three semitones.
+    #[serde(rename = "m3")]
+    MinorThird,
+
+    /// A major third, four semitones.
+    #[serde(rename = "M3")]
+    MajorThird,
+
+    /// A perfect fourth, five semitones.
+    #[serde(rename = "P4")]
+    PerfectFourth,
+
+    /// A diminished fifth, six semitones.
+    #[serde(rename = "d5")]
+    DiminishedFifth,
+
+    /// A perfect fifth, seven semitones.
+    #[serde(rename = "P5")]
+    PerfectFifth,
+
+    /// A minor sixth, eight semitones.
+    #[serde(rename = "m6")]
+    MinorSixth,
+
+    /// A major sixth, nine semitones.
+    #[serde(rename = "M6")]
+    MajorSixth,
+
+    /// A minor seventh, ten semitones.
+    #[serde(rename = "m7")]
+    MinorSeventh,
+
+    /// A major seventh, eleven semitones.
+    #[serde(rename = "M7")]
+    MajorSeventh,
+
+    /// An octave, twelve semitones.
+    #[serde(rename = "P8")]
+    Octave,
+
+    /// A minor ninth, an octave and a minor second.
+    #[serde(rename = "m9")]
+    MinorNinth,
+
+    /// A major ninth, an octave and a major second.
+    #[serde(rename = "M9")]
+    MajorNinth,
+
+    /// A minor tenth, an octave and a minor third.
+    #[serde(rename = "m10")]
+    MinorTenth,
+
+    /// A major tenth, an octave and a major third.
+    #[serde(rename = "M10")]
+    MajorTenth,
+
+    /// A perfect eleventh, an octave and a perfect fourth.
+    #[serde(rename = "P11")]
+    PerfectEleventh,
+
+    /// A perfect twelfth, an octave and a perfect fifth.
+    #[serde(rename = "P12")]
+    PerfectTwelfth,
+
+    /// A minor thirteenth, an octave and a minor sixth.
+    #[serde(rename = "m13")]
+    MinorThirteenth,
+
+    /// A major thirteenth, an octave and a major sixth.
+    #[serde(rename = "M13")]
+    MajorThirteenth,
+
+    /// A minor fourteenth, an octave and a minor seventh.
+    #[serde(rename = "m14")]
+    MinorFourteenth,
+
+    /// A major fourteenth, an octave and a major seventh.
+    #[serde(rename = "M14")]
+    MajorFourteenth,
+
+    /// Two octaves, twenty-four semitones.
+    #[serde(rename = "P15")]
+    TwoOctaves,
+}
+
+impl Interval {
+    /// All the intervals, in order of their size.
+    const ALL: [Interval; 24] = [
+        Interval::Unison,
+        Interval::MinorSecond,
+        Interval::MajorSecond,
+        Interval::MinorThird,
+        Interval::MajorThird,
+        Interval::PerfectFourth,
+        Interval::DiminishedFifth,
+        Interval::PerfectFifth,
+        Interval::MinorSixth,
+        Interval::MajorSixth,
+        Interval::MinorSeventh,
+        Interval::MajorSeventh,
+        Interval::Octave,
+        Interval::MinorNinth,
+        Interval::MajorNinth,
+        Interval::MinorTenth,
+        Interval::MajorTenth,
+        Interval::PerfectEleventh,
+        Interval::PerfectTwelfth,
+        Interval::MinorThirteenth,
+        Interval::MajorThirteenth,
+        Interval::MinorFourteenth,
+        Interval::MajorFourteenth,
+        Interval::TwoOctaves,
+    ];
+
+    /// Returns the short form of the interval used in the catalogs and the metadata.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Interval::Unison => "P1",
+            Interval::MinorSecond => "m2",
+            Interval::MajorSecond => "M2",
+            Interval::MinorThird => "m3",
+            Interval::MajorThird => "M3",
+            Interval::PerfectFourth => "P4",
+            Interval::DiminishedFifth => "d5",
+            Interval::PerfectFifth => "P5",
+            Interval::MinorSixth => "m6",
+            Interval::MajorSixth => "M6",
+            Interval::MinorSeventh => "m7",
+            Interval::MajorSeventh => "M7",
+            Interval::Octave => "P8",
+            Interval::MinorNinth => "m9",
+            Interval::MajorNinth => "M9",
+            Interval::MinorTenth => "m10",
+            Interval::MajorTenth => "M10",
+            Interval::PerfectEleventh => "P11",
+            Interval::PerfectTwelfth => "P12",
+            Interval::MinorThirteenth => "m13",
+            Interval::MajorThirteenth => "M13",
+            Interval::MinorFourteenth => "m14",
+            Interval::MajorFourteenth => "M14",
+            Interval::TwoOctaves => "P15",
+        }
+    }
+
+    /// Returns whether the intervals are all the intervals between the first and the last one, in
+    /// order of size.
+    fn is_range(intervals: &[Interval]) -> bool {
+        let (Some(first), Some(last)) = (intervals.first(), intervals.last()) else {
+            return false;
+        };
+        let start = Self::ALL.iter().position(|i| i == first).unwrap();
+        let end = Self::ALL.iter().position(|i| i == last).unwrap();
+        start <= end && Self::ALL[start..=end] == *intervals
+    }
+}
+
+/// Joins the names into a list of the form "A, B & C".
+pub fn join_names<S: AsRef<str>>(names: &[S]) -> String {
+    match names {
+        [] => String::new(),
+        [name] => name.as_ref().to_string(),
+        [init @ .., last] => {
+            let init: Vec<&str> = init.iter().map(|name| name.as_ref()).collect();
+            format!("{} & {}", init.join(", "), last.as_ref())
+        }
+    }
+}
+
+/// The minimum number of intervals in a range for the lesson to be named after the range instead
+/// of listing every interval.
+const MIN_RANGE_LENGTH: usize = 4;
+
+/// Returns the name of a lesson with the given intervals played in the given directions, using
+/// the vocabulary in the given strings. For example, "Perfect 4th & Octave - Ascending" or "All
+/// intervals from Minor 2nd to Major 3rd - Harmonic".
+pub fn interval_lesson_name(
+    intervals: &[Interval],
+    direction: &[Direction],
+    strings: &Strings,
+) -> String {
+    let intervals_name = if intervals.len() >= MIN_RANGE_LENGTH && Interval::is_range(intervals) {
+        fill(
+            &strings.lesson_all_intervals,
+            &[
+                ("first", strings.interval_name(intervals[0])),
+                (
+                    "last",
+                    strings.interval_name(intervals[intervals.len() - 1]),
+                ),
+            ],
+        )
+    } else {
+        let names: Vec<&str> = intervals
+            .iter()
+            .map(|interval| strings.interval_name(*interval))
+            .collect();
+        join_names(&names)
+    };
+
+    let direction_names: Vec<&str> = direction
+        .iter()
+        .map(|direction| strings.direction_name(*direction))
+        .collect();
+    fill(
+        &strings.lesson_intervals,
+        &[
+            ("intervals", &intervals_name),
+            ("directions", &join_names(&direction_names)),
+        ],
+    )
+}
+
+/// A note value used in the rhythm exercises.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum NoteValue {
+    /// A whole note.
+    #[serde(rename = "whole")]
+    Whole,
+
+    /// A half note.
+    #[serde(rename = "half")]
+    Half,
+
+    /// A quarter note.
+    #[serde(rename = "quarter")]
+    Quarter,
+
+    /// An eighth note.
+    #[serde(rename = "8th")]
+    Eighth,
+
+    /// A sixteenth note.
+    #[serde(rename = "16th")]
+    Sixteenth,
+
+    /// A thirty-second note.
+    #[serde(rename = "32nd")]
+    ThirtySecond,
+}
+
+impl NoteValue {
+    /// Returns the short form of the note value used in the catalogs and the metadata.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            NoteValue::Whole => "whole",
+            NoteValue::Half => "half",
+            NoteValue::Quarter => "quarter",
+            NoteValue::Eighth => "8th",
+            NoteValue::Sixteenth => "16th",
+            NoteValue::ThirtySecond => "32nd",
+        }
+    }
+}
+
+/// Returns the number of bars as written in the names of rhythm lessons, such as "1 bar" or
+/// "4 bars".
+fn bars_name(bars: u32, strings: &Strings) -> String {
+    match bars {
+        1 => strings.lesson_one_bar.clone(),
+        _ => fill(&strings.lesson_bars, &[("count", &bars.to_string())]),
+    }
+}
+
+/// Returns the name of a rhythm lesson, using the vocabulary in the given strings. For example,
+/// "4/4 - 1 bar: 8th & Quarter notes - including rests" or "2/4, 3/4 & 4/4 - 2 bars: Quarter
+/// notes, 8th triplets".
+pub fn rhythm_lesson_name(descriptor: &LessonDescriptor, bars: u32, strings: &Strings) -> String {
+    let names = |values: &[NoteValue]| {
+        let names: Vec<&str> = values
+            .iter()
+            .map(|value| strings.note_value_name(*value))
+            .collect();
+        join_names(&names)
+    };
+    let mut groups = vec![];
+    for (values, template) in [
+        (&descriptor.note_values, &strings.lesson_notes),
+        (&descriptor.dotted_notes, &strings.lesson_dotted_notes),
+        (&descriptor.triplets, &strings.lesson_triplets),
+    ] {
+        if !values.is_empty() {
+            groups.push(fill(template, &[("values", &names(values))]));
+        }
+    }
+
+    let mut name = fill(
+        &strings.lesson_rhythm,
+        &[
+            ("bars", &bars_name(bars, strings)),
+            ("notes", &groups.join(", ")),
+        ],
+    );
+    if !descriptor.time_signatures.is_empty() {
+        name = fill(
+            &strings.lesson_time_signatures,
+            &[
+                ("time_signatures", &join_names(&descriptor.time_signatures)),
+                ("name", &name),
+            ],
+        );
+    }
+    if descriptor.rests == Some(true) {
+        name = fill(&strings.lesson_rests, &[("name", &name)]);
+    }
+    name
+}
+
+/// A scale degree relative to the tonic, written as its movable-do solfège syllable. The chromatic
+/// syllables are included, and the syllables are declared in the order of their position in the
+/// octave above Do. The names shown to the student depend on the selected notation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Syllable {
+    /// The tonic.
+    Do,
+
+    /// The raised tonic.
+    Di,
+
+    /// The lowered second degree.
+    Ra,
+
+    /// The second degree.
+    Re,
+
+    /// The raised second degree.
+    Ri,
+
+    /// The lowered third degree.
+    Me,
+
+    /// The third degree.
+    Mi,
+
+    /// The fourth degree.
+    Fa,
+
+    /// The raised fourth degree.
+    Fi,
+
+    /// The lowered fifth degree.
+    Se,
+
+    /// The fifth degree.
+    So,
+
+    /// The raised fifth degree.
+    Si,
+
+    /// The lowered sixth degree.
+    Le,
+
+    /// The sixth degree.
+    La,
+
+    /// The raised sixth degree.
+    Li,
+
+    /// The lowered seventh degree.
+    Te,
+
+    /// The seventh degree.
+    Ti,
+}
+
+impl Syllable {
+    /// All the syllables, in order of their position in the octave.
+    const ALL: [Syllable; 17] = [
+        Syllable::Do,
+        Syllable::Di,
+        Syllable::Ra,
+        Syllable::Re,
+        Syllable::Ri,
+        Syllable::Me,
+        Syllable::Mi,
+        Syllable::Fa,
+        Syllable::Fi,
+        Syllable::Se,
+        Syllable::So,
+        Syllable::Si,
+        Syllable::Le,
+        Syllable::La,
+        Syllable::Li,
+        Syllable::Te,
+        Syllable::Ti,
+    ];
+
+    /// Returns the diatonic step of the degree, from 0 for Do to 6 for Ti.
+    pub fn step(&self) -> usize {
+        match self {
+            Syllable::Do | Syllable::Di => 0,
+            Syllable::Ra | Syllable::Re | Syllable::Ri => 1,
+            Syllable::Me | Syllable::Mi => 2,
+            Syllable::Fa | Syllable::Fi => 3,
+            Syllable::Se | Syllable::So | Syllable::Si => 4,
+            Syllable::Le | Syllable::La | Syllable::Li => 5,
+            Syllable::Te | Syllable::Ti => 6,
+        }
+    }
+
+    /// Returns the number of semitones between Do and the degree.
+    pub fn semitones(&self) -> u8 {
+        match self {
+            Syllable::Do => 0,
+            Syllable::Di | Syllable::Ra => 1,
+            Syllable::Re => 2,
+            Syllable::Ri | Syllable::Me => 3,
+            Syllable::Mi => 4,
+            Syllable::Fa => 5,
+            Syllable::Fi | Syllable::Se => 6,
+            Syllable::So => 7,
+            Syllable::Si | Syllable::Le => 8,
+            Syllable::La => 9,
+            Syllable::Li | Syllable::Te => 10,
+            Syllable::Ti => 11,
+        }
+    }
+
+    /// Returns the syllable with the given diatonic step and number of semitones above Do, if
+    /// there is one.
+    pub fn from_position(step: usize, semitones: u8) -> Option<Syllable> {
+        Syllable::ALL
+            .into_iter()
+            .find(|syllable| syllable.step() == step && syllable.semitones() == semitones)
+    }
+
+    /// Returns the name of the syllable as shown in EarMaster.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Syllable::Do => "Do",
+            Syllable::Di => "Di",
+            Syllable::Ra => "Ra",
+            Syllable::Re => "Re",
+            Syllable::Ri => "Ri",
+            Syllable::Me => "Me",
+            Syllable::Mi => "Mi",
+            Syllable::Fa => "Fa",
+            Syllable::Fi => "Fi",
+            Syllable::Se => "Se",
+            Syllable::So => "So",
+            Syllable::Si => "Si",
+            Syllable::Le => "Le",
+            Syllable::La => "La",
+            Syllable::Li => "Li",
+            Syllable::Te => "Te",
+            Syllable::Ti => "Ti",
+        }
+    }
+}
+
+impl fmt::Display for Syllable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name().to_lowercase())
+    }
+}
+
+/// Returns the name of a lesson in which the student sings from the starting syllable to the
+/// target syllables, given the names of the syllables in the selected notation and using the
+/// vocabulary in the given strings. For example, "Re - Fa", "Re - Mi and Re - Fa", or "Re to Mi,
+/// Fa & So".
+pub fn syllable_lesson_name<S: AsRef<str>>(
+    start: &str,
+    targets: &[S],
+    strings: &Strings,
+) -> String {
+    match targets {
+        [target] => fill(
+            &strings.lesson_one_target,
+            &[("start", start), ("target", target.as_ref())],
+        ),
+        [first, second] => fill(
+            &strings.lesson_two_targets,
+            &[
+                ("start", start),
+                ("first", first.as_ref()),
+                ("second", second.as_ref()),
+            ],
+        ),
+        _ => fill(
+            &strings.lesson_many_targets,
+            &[("start", start), ("targets", &join_names(targets))],
+        ),
+    }
+}
+
+/// Describes the musical content of a lesson. All the fields are optional, since each activity
+/// only uses some of them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct LessonDescriptor {
+    /// The directions in which the intervals or chords are played.
+    #[serde(default)]
+    pub direction: Vec<Direction>,
+
+    /// The intervals in the lesson, written in their short form (e.g. "m3" or "P5").
+    #[serde(default)]
+    pub intervals: Vec<Interval>,
+
+    /// The chord qualities in the lesson (e.g. "major" or "mi7(b5)").
+    #[serde(default)]
+    pub chord_qualities: Vec<String>,
+
+    /// The time signatures used in the lesson (e.g. "6/8").
+    #[serde(default)]
+    pub time_signatures: Vec<String>,
+
+    /// The number of bars in each exercise.
+    #[serde(default)]
+    pub bars: Option<u32>,
+
+    /// The plain note values used in the lesson (e.g. "quarter" or "8th").
+    #[serde(default)]
+    pub note_values: Vec<NoteValue>,
+
+    /// The note values played as triplets in the lesson.
+    #[serde(default)]
+    pub triplets: Vec<NoteValue>,
+
+    /// The dotted note values used in the lesson.
+    #[serde(default)]
+    pub dotted_notes: Vec<NoteValue>,
+
+    /// Whether the exercises include rests.
+    #[serde(default)]
+    pub rests: Option<bool>,
+
+    /// Whether the exercises include tied notes.
+    #[serde(default)]
+    pub ties: Option<bool>,
+
+    /// Whether the exercises include syncopated rhythms.
+    #[serde(default)]
+    pub syncopation: Option<bool>,
+
+    /// The key of the melodies in the lesson (e.g. "C").
+    #[serde(default)]
+    pub key: Option<String>,
+
+    /// The mode of the melodies in the lesson (e.g. "major" or "harmonic_minor").
+    #[serde(default)]
+    pub mode: Option<String>,
+
+    /// The number of tones in each exercise.
+    #[serde(default)]
+    pub tones: Option<u32>,
+
+    /// The syllable from which the intervals are sung.
+    #[serde(default)]
+    pub start_syllable: Option<Syllable>,
+
+    /// The syllables to which the intervals are sung.
+    #[serde(default)]
+    pub target_syllables: Vec<Syllable>,
+
+    /// The scale degrees used in the melodies, written as movable-do syllables.
+    #[serde(default)]
+    pub degrees: Vec<Syllable>,
+}
+
+impl LessonDescriptor {
+    /// Returns the descriptor as lesson metadata. Only the fields with a value are included.
+    pub fn metadata(&self) -> BTreeMap<String, Vec<String>> {
+        let mut metadata = BTreeMap::new();
+        let mut insert = |key: &str, values: Vec<String>| {
+            if !values.is_empty() {
+                metadata.insert(key.to_string(), values);
+            }
+        };
+
+        insert(
+            "direction",
+            self.direction.iter().map(|d| d.to_string()).collect(),
+        );
+        insert(
+            "interval",
+            self.intervals
+                .iter()
+                .map(|i| i.short_name().to_string())
+                .collect(),
+        );
+        insert("chord_quality", self.chord_qualities.clone());
+        insert("time_signature", self.time_signatures.clone());
+        insert("bars", self.bars.iter().map(|b| b.to_string()).collect());
+        let note_names =
+            |values: &[NoteValue]| values.iter().map(|v| v.short_name().to_string()).collect();
+        insert("note_value", note_names(&self.note_values));
+        insert("triplet", note_names(&self.triplets));
+        insert("dotted_note", note_names(&self.dotted_notes));
+        insert("rests", self.rests.iter().map(|r| r.to_string()).collect());
+        insert("ties", self.ties.iter().map(|t| t.to_string()).collect());
+        insert(
+            "syncopation",
+            self.syncopation.iter().map(|s| s.to_string()).collect(),
+        );
+        insert(
+            &MusicMetadata::Key.to_string(),
+            self.key.iter().cloned().collect(),
+        );
+        insert("mode", self.mode.iter().cloned().collect());
+        insert("tones", self.tones.iter().map(|t| t.to_string()).collect());
+        insert(
+            "start_syllable",
+            self.start_syllable.iter().map(|s| s.to_string()).collect(),
+        );
+        insert(
+            "target_syllable",
+            self.target_syllables
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+        insert(
+            "scale_degree",
+            self.degrees.iter().map(|d| d.to_string()).collect(),
+        );
+        metadata
+    }
+
+    /// Returns the name of the scale degree in the given notation, in the key and mode of the
+    /// lesson.
+    fn degree_name(&self, degree: Syllable, notation: Notation) -> String {
+        notation.degree_name(degree, self.key.as_deref(), self.mode.as_deref())
+    }
+
+    /// Returns the names of the scale degrees in the given notation.
+    fn degree_names(&self, degrees: &[Syllable], notation: Notation) -> Vec<String> {
+        degrees
+            .iter()
+            .map(|degree| self.degree_name(*degree, notation))
+            .collect()
+    }
+
+    /// Returns the name of a lesson from a template in which the placeholders `{degrees}`,
+    /// `{start}`, and `{targets}` are replaced with the scale degrees in the descriptor, written
+    /// in the given notation, and `{bars}` is replaced with the number of bars, such as "4 bars",
+    /// written with the vocabulary in the given strings.
+    pub fn render_name(&self, template: &str, notation: Notation, strings: &Strings) -> String {
+        let degrees = self.degree_names(&self.degrees, notation).join(", ");
+        let start = self
+            .start_syllable
+            .map(|start| self.degree_name(start, notation))
+            .unwrap_or_default();
+        let targets = join_names(&self.degree_names(&self.target_syllables, notation));
+        fill(
+            template,
+            &[
+                ("degrees", &degrees),
+                ("start", &start),
+                ("targets", &targets),
+                (
+                    "bars",
+                    &self
+                        .bars
+                        .map(|bars| bars_name(bars, strings))
+                        .unwrap_or_default(),
+                ),
+            ],
+        )
+    }
+
+    /// Returns the placeholders in the template that cannot be filled because the descriptor does
+    /// not have the corresponding scale degrees or number of bars.
+    pub fn missing_placeholders(&self, template: &str) -> Vec<&'static str> {
+        [
+            ("{degrees}", self.degrees.is_empty()),
+            ("{start}", self.start_syllable.is_none()),
+            ("{targets}", self.target_syllables.is_empty()),
+            ("{bars}", self.bars.is_none()),
+        ]
+        .into_iter()
+        .filter(|(placeholder, missing)| *missing && template.contains(placeholder))
+        .map(|(placeholder, _)| placeholder)
+        .collect()
+    }
+
+    /// Returns the name generated from the descriptor, with the scale degrees written in the given
+    /// notation and the rest written with the vocabulary in the given strings, if the descriptor
+    /// contains enough information to generate one. Lessons without an explicit name in the catalog
+    /// use this name, so that their name and metadata always match.
+    pub fn generated_name(&self, notation: Notation, strings: &Strings) -> Option<String> {
+        if !self.intervals.is_empty() && !self.direction.is_empty() {
+            return Some(interval_lesson_name(
+                &self.intervals,
+                &self.direction,
+                strings,
+            ));
+        }
+        if let (Some(start), false) = (self.start_syllable, self.target_syllables.is_empty()) {
+            return Some(syllable_lesson_name(
+                &self.degree_name(start, notation),
+                &self.degree_names(&self.target_syllables, notation),
+                strings,
+            ));
+        }
+        if !self.degrees.is_empty() {
+            return Some(self.degree_names(&self.degrees, notation).join(", "));
+        }
+        let has_notes = !self.note_values.is_empty()
+            || !self.triplets.is_empty()
+            || !self.dotted_notes.is_empty();
+        if let (Some(bars), true) = (self.bars, has_notes) {
+            return Some(rhythm_lesson_name(self, bars, strings));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{
+        interval_lesson_name, rhythm_lesson_name, syllable_lesson_name, Direction, Interval,
+        LessonDescriptor, NoteValue, Syllable,
+    };
+    use crate::earmaster::{locale::Strings, notation::Notation};
+
+    #[test]
+    fn metadata_skips_empty_fields() {
+        let descriptor = LessonDescriptor {
+            direction: vec![Direction::Harmonic],
+            intervals: vec![Interval::PerfectFourth, Interval::PerfectFifth],
+            bars: Some(2),
+            rests: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            descriptor.metadata(),
+            BTreeMap::from([
+                ("bars".to_string(), vec!["2".to_string()]),
+                ("direction".to_string(), vec!["harmonic".to_string()]),
+                (
+                    "interval".to_string(),
+                    vec!["P4".to_string(), "P5".to_string()]
+                ),
+                ("rests".to_string(), vec!["false".to_string()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn interval_lesson_names() {
+        let strings = Strings::default();
+        assert_eq!(
+            interval_lesson_name(
+                &[Interval::PerfectFourth, Interval::Octave],
+                &[Direction::Ascending],
+                &strings
+            ),
+            "Perfect 4th & Octave - Ascending"
+        );
+        assert_eq!(
+            interval_lesson_name(
+                &[
+                    Interval::MinorSecond,
+                    Interval::MajorSecond,
+                    Interval::MinorThird,
+                    Interval::MajorThird
+                ],
+                &[
+                    Direction::Ascending,
+                    Direction::Descending,
+                    Direction::Harmonic
+                ],
+                &strings
+            ),
+            "All intervals from Minor 2nd to Major 3rd - Ascending, Descending & Harmonic"
+        );
+    }
+
+    #[test]
+    fn rhythm_lesson_names() {
+        let strings = Strings::default();
+        let descriptor = LessonDescriptor {
+            time_signatures: vec!["6/8".to_string()],
+            note_values: vec![NoteValue::Eighth],
+            dotted_notes: vec![NoteValue::Quarter],
+            rests: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            rhythm_lesson_name(&descriptor, 2, &strings),
+            "6/8 - 2 bars: 8th notes, Quarter dotted notes - including rests"
+        );
+        assert_eq!(
+            LessonDescriptor {
+                bars: Some(1),
+                triplets: vec![NoteValue::Half],
+                ..Default::default()
+            }
+            .generated_name(Notation::MovableDo, &strings),
+            Some("1 bar: Half triplets".to_string())
+        );
+        assert_eq!(
+            descriptor.missing_placeholders("6/8 - {bars}: 8th Notes"),
+            vec!["{bars}"]
+        );
+        assert_eq!(
+            LessonDescriptor {
+                bars: Some(4),
+                ..descriptor
+            }
+            .render_name("6/8 - {bars}: 8th Notes", Notation::MovableDo, &strings),
+            "6/8 - 4 bars: 8th Notes"
+        );
+    }
+
+    #[test]
+    fn syllable_lesson_names() {
+        let strings = Strings::default();
+        assert_eq!(syllable_lesson_name("Re", &["Fa"], &strings), "Re - Fa");
+        assert_eq!(
+            syllable_lesson_name("Re", &["Mi", "Fa"], &strings),
+            "Re - Mi and Re - Fa"
+        );
+        let descriptor = LessonDescriptor {
+            direction: vec![Direction::Descending],
+            start_syllable: Some(Syllable::So),
+            target_syllables: vec![Syllable::Mi, Syllable::Re, Syllable::Do],
+            ..Default::default()
+        };
+        assert_eq!(
+            descriptor.generated_name(Notation::MovableDo, &strings),
+            Some("So to Mi, Re & Do".to_string())
+        );
+        assert_eq!(
+            descriptor.generated_name(Notation::ScaleNumbers, &strings),
+            Some("5 to 3, 2 & 1".to_string())
+        );
+        assert_eq!(
+            descriptor.render_name("All intervals from {start}", Notation::LettersInC, &strings),
+            "All intervals from G"
+        );
+        assert_eq!(
+            descriptor.missing_placeholders("{degrees}"),
+            vec!["{degrees}"]
+        );
+        assert_eq!(
+            descriptor.metadata()["target_syllable"],
+            vec!["mi".to_string(), "re".to_string(), "do".to_string()]
+        );
+    }
+
+    /// Verifies that the names are generated with the vocabulary of the given strings, and that
+    /// the default vocabulary names every interval.
+    #[test]
+    fn translated_lesson_names() {
+        for interval in Interval::ALL {
+            assert!(!Strings::default().interval_name(interval).is_empty());
+        }
+
+        let mut strings = Strings {
+            lesson_all_intervals: "Alle Intervalle von {first} bis {last}".to_string(),
+            lesson_bars: "{count} Takte".to_string(),
+            lesson_notes: "Noten: {values}".to_string(),
+            ..Default::default()
+        };
+        strings
+            .intervals
+            .insert("m2".to_string(), "Kleine Sekunde".to_string());
+        strings
+            .intervals
+            .insert("M3".to_string(), "Große Terz".to_string());
+        strings
+            .directions
+            .insert("harmonic".to_string(), "Harmonisch".to_string());
+        strings
+            .note_values
+            .insert("quarter".to_string(), "Viertel".to_string());
+        assert_eq!(
+            interval_lesson_name(
+                &[
+                    Interval::MinorSecond,
+                    Interval::MajorSecond,
+                    Interval::MinorThird,
+                    Interval::MajorThird
+                ],
+                &[Direction::Harmonic],
+                &strings
+            ),
+            "Alle Intervalle von Kleine Sekunde bis Große Terz - Harmonisch"
+        );
+        let descriptor = LessonDescriptor {
+            bars: Some(2),
+            note_values: vec![NoteValue::Quarter],
+            ..Default::default()
+        };
+        assert_eq!(
+            descriptor.generated_name(Notation::MovableDo, &strings),
+            Some("2 Takte: Noten: Viertel".to_string())
+        );
+    }
+}
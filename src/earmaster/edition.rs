@@ -0,0 +1,367 @@
+//! Contains the profiles of the EarMaster editions. Each edition only includes some of the
+//! activities and units, so the courses can be restricted to the content covered by the editions
+//! available to the student. Course and lesson dependencies that point to excluded content are
+//! rewritten to point to the dependencies of the excluded content instead.
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use ustr::Ustr;
+
+use super::{rewrite_dependencies, EarMasterCourse};
+
+/// The metadata key under which the editions for which a course was built are recorded.
+pub const EDITION_METADATA_KEY: &str = "earmaster_edition";
+
+/// The edition profiles bundled with the binary.
+const BUNDLED_EDITIONS: &str = include_str!("../../catalogs/editions.toml");
+
+/// Describes the activities and units available in an edition of EarMaster.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Edition {
+    /// The ID of the edition, used to select it from the command line.
+    pub id: String,
+
+    /// The name of the edition.
+    pub name: String,
+
+    /// The courses included in full, referenced by their IDs or directory names.
+    #[serde(default)]
+    pub courses: Vec<String>,
+
+    /// The courses of which only some units are included, mapped to the IDs of those units.
+    #[serde(default)]
+    pub units: BTreeMap<String, Vec<String>>,
+
+    /// The ID of another edition with the same content, whose courses and units are used instead
+    /// of listing them again.
+    #[serde(default)]
+    pub same_as: Option<String>,
+}
+
+/// The contents of a file with edition profiles.
+#[derive(Deserialize)]
+struct EditionFile {
+    /// The edition profiles in the file.
+    editions: Vec<Edition>,
+}
+
+/// Returns whether the given name refers to the course.
+fn matches(course: &EarMasterCourse, name: &str) -> bool {
+    course.id == name || course.directory_name == name
+}
+
+impl Edition {
+    /// Returns the units of the course included in the edition. The outer option is `None` if the
+    /// course is excluded, and the inner option is `None` if all the units are included.
+    fn course_units(&self, course: &EarMasterCourse) -> Option<Option<&[String]>> {
+        if self.courses.iter().any(|name| matches(course, name)) {
+            return Some(None);
+        }
+        self.units
+            .iter()
+            .find(|(name, _)| matches(course, name))
+            .map(|(_, units)| Some(units.as_slice()))
+    }
+
+    /// Returns whether the course is at least partially included in the edition.
+    pub fn includes_course(&self, course: &EarMasterCourse) -> bool {
+        self.course_units(course).is_some()
+    }
+
+    /// Returns the problems with the profile, such as references to unknown courses or units.
+    pub fn issues(&self, courses: &[EarMasterCourse]) -> Vec<String> {
+        let mut issues = vec![];
+        for name in self.courses.iter().chain(self.units.keys()) {
+            if !courses.iter().any(|course| matches(course, name)) {
+                issues.push(format!("edition {}: unknown course {}", self.id, name));
+            }
+        }
+        for (name, units) in &self.units {
+            if self.courses.iter().any(|full| full == name) {
+                issues.push(format!(
+                    "edition {}: course {} is listed in both courses and units",
+                    self.id, name
+                ));
+            }
+            let Some(course) = courses.iter().find(|course| matches(course, name)) else {
+                continue;
+            };
+            for unit in units {
+                if !course.lessons.iter().any(|lesson| lesson.unit_id() == unit) {
+                    issues.push(format!(
+                        "edition {}: course {} has no unit {}",
+                        self.id, name, unit
+                    ));
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// Returns the problems with the given set of edition profiles as a whole, such as EarMaster
+/// courses that are not included in any edition and would be left out of every build restricted
+/// to editions. The courses of other apps are not restricted by the editions, so they are skipped.
+pub fn coverage_issues(editions: &[Edition], courses: &[EarMasterCourse]) -> Vec<String> {
+    courses
+        .iter()
+        .filter(|course| course.app.is_none())
+        .filter(|course| {
+            !editions
+                .iter()
+                .any(|edition| edition.includes_course(course))
+        })
+        .map(|course| {
+            format!(
+                "course {} is not included in any edition",
+                course.directory_name
+            )
+        })
+        .collect()
+}
+
+/// Returns the courses restricted to the content included in at least one of the given editions.
+/// The editions for which each course was built are recorded in its metadata. If no edition is
+/// given, the courses are returned unchanged.
+pub fn restrict_courses(
+    courses: &[EarMasterCourse],
+    editions: &[Edition],
+) -> Result<Vec<EarMasterCourse>> {
+    if editions.is_empty() {
+        return Ok(courses.to_vec());
+    }
+    for edition in editions {
+        let issues = edition.issues(courses);
+        if !issues.is_empty() {
+            bail!("invalid edition profile:\n{}", issues.join("\n"));
+        }
+    }
+
+    // Compute the included units of each course, where `None` means all of them.
+    let mut included_units: Vec<(&EarMasterCourse, Option<HashSet<&str>>)> = vec![];
+    for course in courses {
+        // The editions of EarMaster do not apply to the courses of other apps.
+        if course.app.is_some() {
+            included_units.push((course, None));
+            continue;
+        }
+        let mut units: Option<Option<HashSet<&str>>> = None;
+        for edition in editions {
+            units = match (units, edition.course_units(course)) {
+                (units, None) => units,
+                (Some(None), _) | (_, Some(None)) => Some(None),
+                (None, Some(Some(new))) => Some(Some(new.iter().map(String::as_str).collect())),
+                (Some(Some(mut existing)), Some(Some(new))) => {
+                    existing.extend(new.iter().map(String::as_str));
+                    Some(Some(existing))
+                }
+            };
+        }
+        if let Some(units) = units {
+            included_units.push((course, units));
+        }
+    }
+
+    let included_courses: HashSet<Ustr> =
+        included_units.iter().map(|(course, _)| course.id).collect();
+    let course_dependencies: HashMap<Ustr, &[Ustr]> = courses
+        .iter()
+        .map(|course| (course.id, course.dependencies.as_slice()))
+        .collect();
+
+    let mut restricted = vec![];
+    for (course, units) in included_units {
+        let mut course = course.clone();
+        course.dependencies = rewrite_dependencies(
+            &course.dependencies,
+            &included_courses,
+            &course_dependencies,
+        );
+
+        if let Some(units) = units {
+            course.retain_lessons(|lesson| units.contains(lesson.unit_id()));
+        }
+
+        if course.app.is_some() {
+            restricted.push(course);
+            continue;
+        }
+        let edition_ids = editions
+            .iter()
+            .filter(|edition| edition.includes_course(&course))
+            .map(|edition| edition.id.clone())
+            .collect();
+        course
+            .metadata
+            .get_or_insert_with(BTreeMap::new)
+            .insert(EDITION_METADATA_KEY.to_string(), edition_ids);
+        restricted.push(course);
+    }
+    Ok(restricted)
+}
+
+/// Parses the contents of a file with edition profiles. The source is used to give context to any
+/// errors. The editions with the same content as another edition get the courses and units of that
+/// edition.
+pub fn parse_editions(source: &str, contents: &str) -> Result<Vec<Edition>> {
+    let file: EditionFile = toml::from_str(contents)
+        .with_context(|| format!("failed to parse edition profiles {}", source))?;
+    let mut editions = file.editions.clone();
+    for edition in &mut editions {
+        let Some(other_id) = &edition.same_as else {
+            continue;
+        };
+        if !edition.courses.is_empty() || !edition.units.is_empty() {
+            bail!(
+                "{}: edition {} cannot list courses or units since it is the same as {}",
+                source,
+                edition.id,
+                other_id
+            );
+        }
+        let Some(other) = file.editions.iter().find(|other| other.id == *other_id) else {
+            bail!(
+                "{}: edition {} is the same as unknown edition {}",
+                source,
+                edition.id,
+                other_id
+            );
+        };
+        if other.same_as.is_some() {
+            bail!(
+                "{}: edition {} is the same as {}, which is itself the same as another edition",
+                source,
+                edition.id,
+                other_id
+            );
+        }
+        edition.courses = other.courses.clone();
+        edition.units = other.units.clone();
+    }
+    Ok(editions)
+}
+
+/// Reads and parses the edition profiles at the given path.
+pub fn load_editions(path: &Path) -> Result<Vec<Edition>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read edition profiles {}", path.display()))?;
+    parse_editions(&path.display().to_string(), &contents)
+}
+
+/// Returns the edition profiles bundled with the binary.
+pub fn bundled_editions() -> Result<Vec<Edition>> {
+    parse_editions("editions.toml", BUNDLED_EDITIONS)
+}
+
+/// Returns the editions with the given IDs.
+pub fn select_editions(editions: &[Edition], ids: &[String]) -> Result<Vec<Edition>> {
+    ids.iter()
+        .map(|id| {
+            editions
+                .iter()
+                .find(|edition| edition.id == *id)
+                .cloned()
+                .with_context(|| format!("unknown edition {}", id))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::Ustr;
+
+    use super::{bundled_editions, coverage_issues, parse_editions, restrict_courses};
+    use crate::earmaster::catalog::{bundled_catalogs, parse_catalog};
+
+    /// Verifies that excluded courses and units are removed and that the dependencies on them are
+    /// rewritten.
+    #[test]
+    fn restricts_courses() -> anyhow::Result<()> {
+        let courses = vec![
+            parse_catalog(
+                "a.toml",
+                r#"
+                    id = "a"
+                    name = "A"
+                    directory_name = "a"
+                    lessons = [{ id = "1.1", name = "A" }]
+                "#,
+            )?,
+            parse_catalog(
+                "b.toml",
+                r#"
+                    id = "b"
+                    name = "B"
+                    directory_name = "b"
+                    dependencies = ["a"]
+                    lessons = [{ id = "1.1", name = "B" }]
+                "#,
+            )?,
+            parse_catalog(
+                "c.toml",
+                r#"
+                    id = "c"
+                    name = "C"
+                    directory_name = "c"
+                    dependencies = ["b"]
+                    lessons = [
+                        { id = "1.1", name = "C1" },
+                        { id = "2.1", name = "C2", dependencies = ["1.1"] },
+                        { id = "3.1", name = "C3", summary = true, dependencies = ["2.1"] },
+                    ]
+                "#,
+            )?,
+        ];
+        let editions = parse_editions(
+            "editions.toml",
+            r#"
+                [[editions]]
+                id = "basic"
+                name = "Basic"
+                courses = ["a"]
+                units = { c = ["1", "3"] }
+            "#,
+        )?;
+
+        assert_eq!(
+            coverage_issues(&editions, &courses),
+            vec!["course b is not included in any edition".to_string()]
+        );
+
+        let restricted = restrict_courses(&courses, &editions)?;
+        assert_eq!(restricted.len(), 2);
+        assert_eq!(restricted[1].dependencies, vec![Ustr::from("a")]);
+        let lessons = &restricted[1].lessons;
+        assert_eq!(lessons.len(), 2);
+        assert_eq!(lessons[1].dependencies, vec![Ustr::from("1.1")]);
+        assert!(lessons[1].superseded_lessons().is_empty());
+        assert_eq!(
+            restricted[1].metadata.as_ref().unwrap()["earmaster_edition"],
+            vec!["basic".to_string()]
+        );
+        Ok(())
+    }
+
+    /// Verifies that the bundled profiles only reference the content of the bundled catalogs, that
+    /// every EarMaster course is included in at least one edition, and that the editions with the
+    /// same content as another edition are resolved.
+    #[test]
+    fn bundled_editions_are_valid() -> anyhow::Result<()> {
+        let courses = bundled_catalogs()?;
+        let editions = bundled_editions()?;
+        for edition in &editions {
+            assert_eq!(edition.issues(&courses), Vec::<String>::new());
+            assert!(!restrict_courses(&courses, std::slice::from_ref(edition))?.is_empty());
+        }
+        assert_eq!(coverage_issues(&editions, &courses), Vec::<String>::new());
+
+        let edition = |id: &str| editions.iter().find(|edition| edition.id == id).unwrap();
+        assert_eq!(edition("cloud").courses, edition("pro").courses);
+        Ok(())
+    }
+}
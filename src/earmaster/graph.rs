@@ -0,0 +1,274 @@
+//! Exports the dependency graph described by the EarMaster catalogs in the Graphviz DOT and Mermaid
+//! formats. Edges point from a prerequisite to the course or lesson that depends on it, so that
+//! following the arrows traces the practice path.
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use ustr::Ustr;
+
+use super::{reference::is_qualified, EarMasterCourse};
+
+/// The formats in which the graph can be exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    /// The Graphviz DOT format.
+    Dot,
+
+    /// The Mermaid flowchart format.
+    Mermaid,
+}
+
+/// A node in the graph.
+struct Node {
+    /// The ID of the course or lesson.
+    id: Ustr,
+
+    /// The label shown for the node.
+    label: String,
+}
+
+/// A group of nodes drawn together.
+struct Cluster {
+    /// The label shown for the cluster.
+    label: String,
+
+    /// The nodes in the cluster.
+    nodes: Vec<Node>,
+}
+
+/// A format-independent representation of the graph to export.
+struct Graph {
+    /// The nodes that do not belong to any cluster.
+    nodes: Vec<Node>,
+
+    /// The clusters of nodes.
+    clusters: Vec<Cluster>,
+
+    /// The edges of the graph, as pairs of prerequisite and dependent IDs.
+    edges: Vec<(Ustr, Ustr)>,
+}
+
+impl Graph {
+    /// Returns an identifier for the node with the given ID which is valid in Mermaid. The prefix
+    /// avoids identifiers starting with a digit, such as those of lessons.
+    fn mermaid_id(id: &Ustr) -> String {
+        let sanitized: String = id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("n_{}", sanitized)
+    }
+
+    /// Escapes a label so that it can be used inside a quoted string.
+    fn escape(label: &str, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => label.replace('\\', "\\\\").replace('"', "\\\""),
+            GraphFormat::Mermaid => label.replace('"', "#quot;"),
+        }
+    }
+
+    /// Renders the graph in the DOT format.
+    fn to_dot(&self) -> String {
+        let mut output = String::new();
+        let node_line = |node: &Node| {
+            format!(
+                "\"{}\" [label=\"{}\"];",
+                node.id,
+                Self::escape(&node.label, GraphFormat::Dot)
+            )
+        };
+
+        writeln!(output, "digraph earmaster {{").unwrap();
+        writeln!(output, "    rankdir=LR;").unwrap();
+        writeln!(output, "    node [shape=box];").unwrap();
+        for node in &self.nodes {
+            writeln!(output, "    {}", node_line(node)).unwrap();
+        }
+        for (index, cluster) in self.clusters.iter().enumerate() {
+            writeln!(output, "    subgraph cluster_{} {{", index).unwrap();
+            writeln!(
+                output,
+                "        label=\"{}\";",
+                Self::escape(&cluster.label, GraphFormat::Dot)
+            )
+            .unwrap();
+            for node in &cluster.nodes {
+                writeln!(output, "        {}", node_line(node)).unwrap();
+            }
+            writeln!(output, "    }}").unwrap();
+        }
+        for (from, to) in &self.edges {
+            writeln!(output, "    \"{}\" -> \"{}\";", from, to).unwrap();
+        }
+        writeln!(output, "}}").unwrap();
+        output
+    }
+
+    /// Renders the graph in the Mermaid format.
+    fn to_mermaid(&self) -> String {
+        let mut output = String::new();
+        let node_line = |node: &Node| {
+            format!(
+                "{}[\"{}\"]",
+                Self::mermaid_id(&node.id),
+                Self::escape(&node.label, GraphFormat::Mermaid)
+            )
+        };
+
+        writeln!(output, "flowchart LR").unwrap();
+        for node in &self.nodes {
+            writeln!(output, "    {}", node_line(node)).unwrap();
+        }
+        for (index, cluster) in self.clusters.iter().enumerate() {
+            writeln!(
+                output,
+                "    subgraph cluster_{} [\"{}\"]",
+                index,
+                Self::escape(&cluster.label, GraphFormat::Mermaid)
+            )
+            .unwrap();
+            for node in &cluster.nodes {
+                writeln!(output, "        {}", node_line(node)).unwrap();
+            }
+            writeln!(output, "    end").unwrap();
+        }
+        for (from, to) in &self.edges {
+            writeln!(
+                output,
+                "    {} --> {}",
+                Self::mermaid_id(from),
+                Self::mermaid_id(to)
+            )
+            .unwrap();
+        }
+        output
+    }
+
+    /// Renders the graph in the given format.
+    fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+        }
+    }
+}
+
+/// Returns the graph of the dependencies between the given courses.
+pub fn course_graph(courses: &[EarMasterCourse], format: GraphFormat) -> String {
+    let graph = Graph {
+        nodes: courses
+            .iter()
+            .map(|course| Node {
+                id: course.id,
+                label: course.name.clone(),
+            })
+            .collect(),
+        clusters: vec![],
+        edges: courses
+            .iter()
+            .flat_map(|course| {
+                course
+                    .dependencies
+                    .iter()
+                    .map(|dependency| (*dependency, course.id))
+            })
+            .collect(),
+    };
+    graph.render(format)
+}
+
+/// Returns the graph of the dependencies between the lessons of the given course. Lessons are
+/// clustered by their unit, which is labeled with its title if the course lists it.
+pub fn lesson_graph(course: &EarMasterCourse, format: GraphFormat) -> String {
+    let mut clusters: Vec<Cluster> = vec![];
+    for lesson in &course.lessons {
+        let label = match course.unit(lesson) {
+            Some(unit) => format!("Unit {} - {}", unit.id, unit.title),
+            None => format!("Unit {}", lesson.unit_id()),
+        };
+        let node = Node {
+            id: lesson.id,
+            label: format!("{} {}", lesson.id, lesson.name),
+        };
+        match clusters.iter_mut().find(|cluster| cluster.label == label) {
+            Some(cluster) => cluster.nodes.push(node),
+            None => clusters.push(Cluster {
+                label,
+                nodes: vec![node],
+            }),
+        }
+    }
+
+    // The lessons of other courses on which the lessons depend are drawn outside the clusters.
+    let mut nodes: Vec<Node> = vec![];
+    for dependency in course
+        .lessons
+        .iter()
+        .flat_map(|lesson| lesson.dependencies.iter())
+        .filter(|dependency| is_qualified(dependency))
+    {
+        if !nodes.iter().any(|node| node.id == *dependency) {
+            nodes.push(Node {
+                id: *dependency,
+                label: dependency.to_string(),
+            });
+        }
+    }
+
+    let graph = Graph {
+        nodes,
+        clusters,
+        edges: course
+            .lessons
+            .iter()
+            .flat_map(|lesson| {
+                lesson
+                    .dependencies
+                    .iter()
+                    .map(|dependency| (*dependency, lesson.id))
+            })
+            .collect(),
+    };
+    graph.render(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{lesson_graph, GraphFormat};
+    use crate::earmaster::catalog::parse_catalog;
+
+    #[test]
+    fn lesson_graph_mermaid() -> anyhow::Result<()> {
+        let course = parse_catalog(
+            "test.toml",
+            r#"
+                id = "test"
+                name = "Test"
+                directory_name = "test"
+                units = [{ id = "1", title = "First" }]
+                lessons = [
+                    { id = "1.1", name = "A" },
+                    { id = "2.1", name = "B \"quoted\"", dependencies = ["1.1", "other::1.2"] },
+                ]
+            "#,
+        )?;
+        assert_eq!(
+            lesson_graph(&course, GraphFormat::Mermaid),
+            indoc! {r#"
+                flowchart LR
+                    n_other__1_2["other::1.2"]
+                    subgraph cluster_0 ["Unit 1 - First"]
+                        n_1_1["1.1 A"]
+                    end
+                    subgraph cluster_1 ["Unit 2"]
+                        n_2_1["2.1 B #quot;quoted#quot;"]
+                    end
+                    n_1_1 --> n_2_1
+                    n_other__1_2 --> n_2_1
+            "#}
+        );
+        Ok(())
+    }
+}
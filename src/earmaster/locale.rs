@@ -0,0 +1,680 @@
+//! Contains the translations of the courses into the languages in which EarMaster is available.
+//! Students using a localized install of EarMaster see different activity, unit, and lesson names
+//! on screen, so a locale translates those names along with the templates of the generated
+//! instructions and flashcards and the vocabulary of the generated lesson names. Only names and
+//! text are translated, so the IDs of the courses, lessons, and exercises are identical across
+//! locales and the practice history is kept when switching between them. Anything without a
+//! translation is left in English, and the bundled locales leave the course content untranslated.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    descriptor::{Direction, Interval, NoteValue},
+    rubric::ActivityKind,
+    EarMasterCourse,
+};
+
+/// The locale in which the catalogs are written, which needs no translation.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// The locales bundled with the binary, as tuples of file name and contents.
+const BUNDLED_LOCALES: &[(&str, &str)] = &[
+    ("de.toml", include_str!("../../catalogs/locales/de.toml")),
+    ("es.toml", include_str!("../../catalogs/locales/es.toml")),
+    ("fr.toml", include_str!("../../catalogs/locales/fr.toml")),
+];
+
+/// Replaces every placeholder of the form `{key}` in the template with its value.
+pub fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |text, (key, value)| {
+            text.replace(&format!("{{{}}}", key), value)
+        })
+}
+
+/// The templates of the text written to the generated instructions and flashcards, and the
+/// vocabulary of the lesson names generated from the descriptors. Placeholders are written as
+/// `{key}` and replaced with the values listed in the documentation of each field.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Strings {
+    /// The description of a course. Placeholders: `app` and `activity`.
+    pub course_description: String,
+
+    /// The instructions of a course. Placeholders: `app`, `activity`, and `version`.
+    pub course_instructions: String,
+
+    /// The sentence introducing the list of units in the course instructions.
+    pub course_units: String,
+
+    /// The title of a unit. Placeholders: `id` and `title`.
+    pub unit: String,
+
+    /// The front of the exercise cards. Placeholders: `app`, `activity`, `name`, and `number`.
+    pub exercise_front: String,
+
+    /// The sentence introducing the score table on the back of the exercise cards. Placeholders:
+    /// `app`.
+    pub rubric_intro: String,
+
+    /// The header of the column with the results reported by the app. Placeholders: `app`.
+    pub rubric_result: String,
+
+    /// The header of the column with the scores.
+    pub rubric_score: String,
+
+    /// The range of the highest score. Placeholders: `min`.
+    pub rubric_min: String,
+
+    /// The range of the intermediate scores. Placeholders: `min` and `max`.
+    pub rubric_range: String,
+
+    /// The range of the lowest score. Placeholders: `max`.
+    pub rubric_max: String,
+
+    /// The rule to lower the score after too many attempts. Placeholders: `attempts`.
+    pub rubric_attempts: String,
+
+    /// The number of attempts when only one attempt is allowed.
+    pub rubric_one_attempt: String,
+
+    /// The number of attempts when several are allowed. Placeholders: `count`.
+    pub rubric_many_attempts: String,
+
+    /// The guidance for identification activities.
+    pub guidance_identification: String,
+
+    /// The guidance for dictation activities.
+    pub guidance_dictation: String,
+
+    /// The guidance for singing activities. Placeholders: `app`.
+    pub guidance_singing: String,
+
+    /// The guidance for performance activities.
+    pub guidance_performance: String,
+
+    /// The front of the exercise cards of musictheory.net, which is followed by the preset of the
+    /// lesson. Placeholders: `app`, `activity`, and `name`.
+    pub musictheory_exercise_front: String,
+
+    /// The instructions of a musictheory.net course. Placeholders: `app` and `activity`.
+    pub musictheory_course_instructions: String,
+
+    /// The sentence introducing the settings of a musictheory.net preset. Placeholders:
+    /// `exercise`.
+    pub musictheory_preset: String,
+
+    /// The name of an interval lesson. Placeholders: `intervals` and `directions`.
+    pub lesson_intervals: String,
+
+    /// The intervals of a lesson with every interval in a range. Placeholders: `first` and `last`.
+    pub lesson_all_intervals: String,
+
+    /// The name of a lesson sung from one syllable to another. Placeholders: `start` and `target`.
+    pub lesson_one_target: String,
+
+    /// The name of a lesson sung from one syllable to either of two others. Placeholders: `start`,
+    /// `first`, and `second`.
+    pub lesson_two_targets: String,
+
+    /// The name of a lesson sung from one syllable to several others. Placeholders: `start` and
+    /// `targets`.
+    pub lesson_many_targets: String,
+
+    /// The name of a rhythm lesson. Placeholders: `bars` and `notes`.
+    pub lesson_rhythm: String,
+
+    /// The name of a rhythm lesson in the given time signatures. Placeholders: `time_signatures`
+    /// and `name`.
+    pub lesson_time_signatures: String,
+
+    /// The name of a rhythm lesson with rests. Placeholders: `name`.
+    pub lesson_rests: String,
+
+    /// The plain note values of a rhythm lesson. Placeholders: `values`.
+    pub lesson_notes: String,
+
+    /// The dotted note values of a rhythm lesson. Placeholders: `values`.
+    pub lesson_dotted_notes: String,
+
+    /// The note values played as triplets in a rhythm lesson. Placeholders: `values`.
+    pub lesson_triplets: String,
+
+    /// The length of the exercises of a lesson with a single bar.
+    pub lesson_one_bar: String,
+
+    /// The length of the exercises of a lesson with several bars. Placeholders: `count`.
+    pub lesson_bars: String,
+
+    /// The names of the intervals, indexed by their short form (e.g. "m3").
+    pub intervals: BTreeMap<String, String>,
+
+    /// The names of the directions, indexed by their short form (e.g. "ascending").
+    pub directions: BTreeMap<String, String>,
+
+    /// The names of the note values, indexed by their short form (e.g. "8th").
+    pub note_values: BTreeMap<String, String>,
+}
+
+/// Returns the map from the short forms to the names of the vocabulary.
+fn names(names: &[(&str, &str)]) -> BTreeMap<String, String> {
+    names
+        .iter()
+        .map(|(short_name, name)| (short_name.to_string(), name.to_string()))
+        .collect()
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self {
+            course_description: "Practice {app} activity {activity}".to_string(),
+            course_instructions: "This course contains the exercises from the {activity} \n\
+                activity in {app} {version}. The exercises are referenced by the same\n\
+                numbers as in {app}. If you do not have a copy of {app}, you\n\
+                can add this course to the blacklist.\n"
+                .to_string(),
+            course_units: "The course is divided into the following units:".to_string(),
+            unit: "Unit {id} - {title}".to_string(),
+            exercise_front: "Work on the following exercise in {app}:\n\
+                - Activity: {activity}\n\
+                - Exercise Name: {name}\n\
+                - Exercise Number: {number}\n"
+                .to_string(),
+            rubric_intro: "Score this exercise using the result reported by {app}:".to_string(),
+            rubric_result: "{app} result".to_string(),
+            rubric_score: "Score".to_string(),
+            rubric_min: "{min}% or more".to_string(),
+            rubric_range: "{min}% to {max}%".to_string(),
+            rubric_max: "Less than {max}%".to_string(),
+            rubric_attempts: "Lower the score by one if you needed {attempts} on most questions."
+                .to_string(),
+            rubric_one_attempt: "more than one attempt".to_string(),
+            rubric_many_attempts: "more than {count} attempts".to_string(),
+            guidance_identification: "Only count the questions you answered without replaying \
+                them more than once."
+                .to_string(),
+            guidance_dictation: "Only count a question as correct if every note was entered \
+                correctly. Replaying the question is expected, but lower the score by one if you \
+                needed to hear it more than three times on most questions."
+                .to_string(),
+            guidance_singing: "{app} accepts small deviations in pitch, so also judge your \
+                intonation. Lower the score by one if you were consistently sharp or flat, or if \
+                you had to slide into most of the notes, even if {app} accepted the answer."
+                .to_string(),
+            guidance_performance: "Lower the score by one if you had to reduce the tempo below \
+                the default to pass the exercise."
+                .to_string(),
+            musictheory_exercise_front: "Work on the following lesson on {app}:\n\
+                - Course: {activity}\n\
+                - Lesson: {name}\n"
+                .to_string(),
+            musictheory_course_instructions: "This course contains lessons based on the free \
+                {activity} \nexercises on {app}. Each lesson uses one of the exercises with the \
+                settings listed\non its flashcard, which are selected on the settings page of the \
+                exercise before\nstarting it.\n"
+                .to_string(),
+            musictheory_preset: "Open the {exercise} exercise and select the following settings \
+                before starting:"
+                .to_string(),
+            lesson_intervals: "{intervals} - {directions}".to_string(),
+            lesson_all_intervals: "All intervals from {first} to {last}".to_string(),
+            lesson_one_target: "{start} - {target}".to_string(),
+            lesson_two_targets: "{start} - {first} and {start} - {second}".to_string(),
+            lesson_many_targets: "{start} to {targets}".to_string(),
+            lesson_rhythm: "{bars}: {notes}".to_string(),
+            lesson_time_signatures: "{time_signatures} - {name}".to_string(),
+            lesson_rests: "{name} - including rests".to_string(),
+            lesson_notes: "{values} notes".to_string(),
+            lesson_dotted_notes: "{values} dotted notes".to_string(),
+            lesson_triplets: "{values} triplets".to_string(),
+            lesson_one_bar: "1 bar".to_string(),
+            lesson_bars: "{count} bars".to_string(),
+            intervals: names(&[
+                ("P1", "Unison"),
+                ("m2", "Minor 2nd"),
+                ("M2", "Major 2nd"),
+                ("m3", "Minor 3rd"),
+                ("M3", "Major 3rd"),
+                ("P4", "Perfect 4th"),
+                ("d5", "Dim 5th"),
+                ("P5", "Perfect 5th"),
+                ("m6", "Minor 6th"),
+                ("M6", "Major 6th"),
+                ("m7", "Minor 7th"),
+                ("M7", "Major 7th"),
+                ("P8", "Octave"),
+                ("m9", "Minor 9th"),
+                ("M9", "Major 9th"),
+                ("m10", "Minor 10th"),
+                ("M10", "Major 10th"),
+                ("P11", "Perfect 11th"),
+                ("P12", "Perfect 12th"),
+                ("m13", "Minor 13th"),
+                ("M13", "Major 13th"),
+                ("m14", "Minor 14th"),
+                ("M14", "Major 14th"),
+                ("P15", "Two Octaves"),
+            ]),
+            directions: names(&[
+                ("ascending", "Ascending"),
+                ("descending", "Descending"),
+                ("harmonic", "Harmonic"),
+            ]),
+            note_values: names(&[
+                ("whole", "Whole"),
+                ("half", "Half"),
+                ("quarter", "Quarter"),
+                ("8th", "8th"),
+                ("16th", "16th"),
+                ("32nd", "32nd"),
+            ]),
+        }
+    }
+}
+
+impl Strings {
+    /// Returns the guidance for the given kind of activity.
+    pub fn guidance(&self, activity: ActivityKind) -> &str {
+        match activity {
+            ActivityKind::Identification => &self.guidance_identification,
+            ActivityKind::Dictation => &self.guidance_dictation,
+            ActivityKind::Singing => &self.guidance_singing,
+            ActivityKind::Performance => &self.guidance_performance,
+        }
+    }
+
+    /// Returns the name of the interval.
+    pub fn interval_name(&self, interval: Interval) -> &str {
+        &self.intervals[interval.short_name()]
+    }
+
+    /// Returns the name of the direction.
+    pub fn direction_name(&self, direction: Direction) -> &str {
+        &self.directions[direction.short_name()]
+    }
+
+    /// Returns the name of the note value.
+    pub fn note_value_name(&self, value: NoteValue) -> &str {
+        &self.note_values[value.short_name()]
+    }
+
+    /// Adds the English names of the vocabulary missing from the translated names, so that
+    /// anything without a translation is left in English.
+    fn add_missing_names(&mut self) {
+        let english = Strings::default();
+        for (names, english_names) in [
+            (&mut self.intervals, english.intervals),
+            (&mut self.directions, english.directions),
+            (&mut self.note_values, english.note_values),
+        ] {
+            for (short_name, name) in english_names {
+                names.entry(short_name).or_insert(name);
+            }
+        }
+    }
+
+    /// Returns the problems with the translated names of the vocabulary, which are indexed by
+    /// short forms unknown to the catalogs.
+    fn issues(&self) -> Vec<String> {
+        let english = Strings::default();
+        let mut issues = vec![];
+        for (kind, names, english_names) in [
+            ("interval", &self.intervals, &english.intervals),
+            ("direction", &self.directions, &english.directions),
+            ("note value", &self.note_values, &english.note_values),
+        ] {
+            for short_name in names.keys() {
+                if !english_names.contains_key(short_name) {
+                    issues.push(format!("unknown {} {}", kind, short_name));
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// The translation of the names in a course.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CourseTranslation {
+    /// The name of the activity.
+    pub name: Option<String>,
+
+    /// The titles of the units, indexed by unit ID.
+    pub units: BTreeMap<String, String>,
+
+    /// The names of the lessons, indexed by lesson ID.
+    pub lessons: BTreeMap<String, String>,
+}
+
+/// A translation of the courses and templates into a language.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Locale {
+    /// The ID of the locale, used to select it from the command line.
+    pub id: String,
+
+    /// The name of the language in the language itself.
+    pub name: String,
+
+    /// The translated templates. Missing templates are left in English.
+    #[serde(default)]
+    pub strings: Strings,
+
+    /// The translations of the courses, indexed by course ID or directory name.
+    #[serde(default)]
+    pub courses: BTreeMap<String, CourseTranslation>,
+
+    /// The name of the file from which the locale was loaded. Used to give context to errors.
+    #[serde(skip)]
+    pub source: String,
+}
+
+/// Returns whether the given name refers to the course.
+fn matches(course: &EarMasterCourse, name: &str) -> bool {
+    course.id == name || course.directory_name == name
+}
+
+impl Locale {
+    /// Returns the translation of the given course, if any.
+    fn course_translation(&self, course: &EarMasterCourse) -> Option<&CourseTranslation> {
+        self.courses
+            .iter()
+            .find(|(name, _)| matches(course, name))
+            .map(|(_, translation)| translation)
+    }
+
+    /// Returns the problems with the locale, such as translations of unknown courses, units,
+    /// lessons, or vocabulary, which usually mean the locale was not updated after a catalog
+    /// changed.
+    pub fn issues(&self, courses: &[EarMasterCourse]) -> Vec<String> {
+        let mut issues: Vec<String> = self
+            .strings
+            .issues()
+            .into_iter()
+            .map(|issue| format!("{}: {}", self.source, issue))
+            .collect();
+        for (name, translation) in &self.courses {
+            let Some(course) = courses.iter().find(|course| matches(course, name)) else {
+                issues.push(format!("{}: unknown course {}", self.source, name));
+                continue;
+            };
+            for unit in translation.units.keys() {
+                if !course.units.iter().any(|known| known.id == *unit) {
+                    issues.push(format!(
+                        "{}: course {} has no unit {}",
+                        self.source, name, unit
+                    ));
+                }
+            }
+            for lesson in translation.lessons.keys() {
+                let is_hidden = course
+                    .hidden_lessons
+                    .iter()
+                    .any(|hidden| hidden.id == lesson.as_str());
+                if !is_hidden
+                    && !course
+                        .lessons
+                        .iter()
+                        .any(|known| known.id == lesson.as_str())
+                {
+                    issues.push(format!(
+                        "{}: course {} has no lesson {}",
+                        self.source, name, lesson
+                    ));
+                }
+            }
+        }
+        issues
+    }
+
+    /// Returns the course with its names and templates translated. The names of the lessons
+    /// without a translation are rendered again with the translated vocabulary. The IDs are not
+    /// changed.
+    pub fn localize_course(&self, course: &EarMasterCourse) -> Result<EarMasterCourse> {
+        let mut localized = course.clone();
+        localized.strings = self.strings.clone();
+        if let Some(translation) = self.course_translation(course) {
+            if let Some(name) = &translation.name {
+                localized.name = name.clone();
+            }
+            for unit in &mut localized.units {
+                if let Some(title) = translation.units.get(&unit.id) {
+                    unit.title = title.clone();
+                }
+            }
+            for lesson in &mut localized.lessons {
+                if let Some(name) = translation.lessons.get(lesson.id.as_str()) {
+                    lesson.name_template = Some(name.clone());
+                }
+            }
+        }
+        localized.render_lesson_names()?;
+        Ok(localized)
+    }
+}
+
+/// Returns the given courses translated with the given locale. If no locale is given, the courses
+/// are returned unchanged. The locale is checked against the catalogs, which are the courses before
+/// the selected release and editions left out any courses or lessons, so that the translations of
+/// the content that was left out are not reported.
+pub fn localize_courses(
+    catalogs: &[EarMasterCourse],
+    courses: &[EarMasterCourse],
+    locale: Option<&Locale>,
+) -> Result<Vec<EarMasterCourse>> {
+    let Some(locale) = locale else {
+        return Ok(courses.to_vec());
+    };
+    let issues = locale.issues(catalogs);
+    if !issues.is_empty() {
+        bail!("invalid locale {}:\n{}", locale.id, issues.join("\n"));
+    }
+    courses
+        .iter()
+        .map(|course| locale.localize_course(course))
+        .collect()
+}
+
+/// Parses the contents of a locale file. The file name is used to give context to any errors.
+pub fn parse_locale(file_name: &str, contents: &str) -> Result<Locale> {
+    let mut locale: Locale = toml::from_str(contents)
+        .with_context(|| format!("failed to parse locale {}", file_name))?;
+    locale.source = file_name.to_string();
+    locale.strings.add_missing_names();
+    Ok(locale)
+}
+
+/// Reads and parses all the locale files in the given directory, sorted by file name.
+pub fn load_locale_directory(directory: &Path) -> Result<Vec<Locale>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)
+        .with_context(|| format!("failed to read locale directory {}", directory.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<_>>()?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "toml")
+    });
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("failed to read locale {}", path.display()))?;
+            parse_locale(&path.display().to_string(), &contents)
+        })
+        .collect()
+}
+
+/// Returns the locales bundled with the binary.
+pub fn bundled_locales() -> Result<Vec<Locale>> {
+    BUNDLED_LOCALES
+        .iter()
+        .map(|(file_name, contents)| parse_locale(file_name, contents))
+        .collect()
+}
+
+/// Returns the locale with the given ID, or `None` for the default locale.
+pub fn select_locale(locales: &[Locale], id: &str) -> Result<Option<Locale>> {
+    if id == DEFAULT_LOCALE {
+        return Ok(None);
+    }
+    match locales.iter().find(|locale| locale.id == id) {
+        Some(locale) => Ok(Some(locale.clone())),
+        None => {
+            let mut available: Vec<&str> = vec![DEFAULT_LOCALE];
+            available.extend(locales.iter().map(|locale| locale.id.as_str()));
+            bail!(
+                "unknown locale {}, available locales: {}",
+                id,
+                available.join(", ")
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{
+        bundled_locales, load_locale_directory, localize_courses, parse_locale, Strings,
+        BUNDLED_LOCALES,
+    };
+    use crate::earmaster::catalog::{bundled_catalogs, parse_catalog};
+
+    /// Verifies that the names, templates, and vocabulary are translated while the IDs are kept,
+    /// and that translations of unknown lessons and vocabulary are reported.
+    #[test]
+    fn localizes_courses() -> anyhow::Result<()> {
+        let courses = vec![parse_catalog(
+            "a.toml",
+            r#"
+                id = "a"
+                name = "Interval Identification"
+                directory_name = "a"
+                units = [{ id = "1", title = "Seconds" }]
+                lessons = [
+                    { id = "1.1", name = "Minor 2nd & Major 2nd" },
+                    { id = "1.2", name = "Untranslated", dependencies = ["1.1"] },
+                    { id = "1.3", dependencies = ["1.2"], intervals = ["m2", "M2"], direction = ["harmonic"] },
+                ]
+            "#,
+        )?];
+        let locale = parse_locale(
+            "de.toml",
+            r#"
+                id = "de"
+                name = "Deutsch"
+
+                [strings]
+                unit = "Lektion {id} - {title}"
+                intervals = { m2 = "Kleine Sekunde", M2 = "Große Sekunde" }
+
+                [courses.a]
+                name = "Intervalle erkennen"
+                units = { "1" = "Sekunden" }
+                lessons = { "1.1" = "Kleine & große Sekunde" }
+            "#,
+        )?;
+
+        let localized = localize_courses(&courses, &courses, Some(&locale))?;
+        let course = &localized[0];
+        assert_eq!(course.id, courses[0].id);
+        assert_eq!(course.name, "Intervalle erkennen");
+        assert_eq!(course.units[0].title, "Sekunden");
+        assert_eq!(course.lessons[0].id, "1.1");
+        assert_eq!(course.lessons[0].name, "Kleine & große Sekunde");
+        assert_eq!(course.lessons[1].name, "Untranslated");
+        assert_eq!(
+            course.lessons[2].name,
+            "Kleine Sekunde & Große Sekunde - Harmonic"
+        );
+        assert_eq!(course.strings.unit, "Lektion {id} - {title}");
+        assert_eq!(course.strings.rubric_score, courses[0].strings.rubric_score);
+
+        let outdated = parse_locale(
+            "fr.toml",
+            r#"
+                id = "fr"
+                name = "Français"
+                courses.a.lessons = { "9.9" = "Inconnue" }
+            "#,
+        )?;
+        assert!(localize_courses(&courses, &courses, Some(&outdated)).is_err());
+
+        let unknown_name = parse_locale(
+            "es.toml",
+            r#"
+                id = "es"
+                name = "Español"
+                strings.intervals = { m99 = "Desconocido" }
+            "#,
+        )?;
+        assert_eq!(
+            unknown_name.issues(&courses),
+            vec!["es.toml: unknown interval m99".to_string()]
+        );
+        Ok(())
+    }
+
+    /// Verifies that the bundled locales are the ones on disk, that they only translate the
+    /// content in the bundled catalogs, and that they translate every template, including the ones
+    /// of other apps such as musictheory.net, and every name of the vocabulary. Templates made only
+    /// of placeholders and punctuation can be the same as in English, but must still be listed.
+    #[test]
+    fn bundled_locales_are_valid() -> anyhow::Result<()> {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("catalogs/locales");
+        let bundled = bundled_locales()?;
+        assert_eq!(load_locale_directory(&directory)?.len(), bundled.len());
+        let courses = bundled_catalogs()?;
+        let english = toml::Value::try_from(Strings::default())?;
+        let has_words = |template: &str| {
+            template.split('{').any(|part| {
+                part.split_once('}')
+                    .map_or(part, |(_, text)| text)
+                    .chars()
+                    .any(char::is_alphabetic)
+            })
+        };
+        for (locale, (_, contents)) in bundled.iter().zip(BUNDLED_LOCALES) {
+            assert_eq!(locale.issues(&courses), Vec::<String>::new());
+            localize_courses(&courses, &courses, Some(locale))?;
+            let file: toml::Value = toml::from_str(contents)?;
+            let strings = &file["strings"];
+            let untranslated: Vec<String> = english
+                .as_table()
+                .unwrap()
+                .iter()
+                .flat_map(|(key, value)| match value {
+                    toml::Value::Table(names) => names
+                        .keys()
+                        .filter(|name| strings.get(key).and_then(|t| t.get(name)).is_none())
+                        .map(|name| format!("{}.{}", key, name))
+                        .collect(),
+                    _ => match strings.get(key) {
+                        Some(translated)
+                            if translated != value || !has_words(value.as_str().unwrap()) =>
+                        {
+                            vec![]
+                        }
+                        _ => vec![key.clone()],
+                    },
+                })
+                .collect();
+            assert!(
+                untranslated.is_empty(),
+                "{} does not translate {:?}",
+                locale.source,
+                untranslated
+            );
+        }
+        Ok(())
+    }
+}
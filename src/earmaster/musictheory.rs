@@ -0,0 +1,195 @@
+//! Contains the support for the free exercises on musictheory.net. The exercises are not divided
+//! into numbered lessons like in EarMaster. Instead, each exercise is configured on its settings
+//! page, so every lesson of a musictheory.net course describes a preset: the exercise to open and
+//! the settings to select before starting it. The courses are otherwise built like the EarMaster
+//! courses, and depend on the EarMaster courses that practice the same skills by ear.
+use std::{collections::BTreeMap, fmt};
+
+use serde::Deserialize;
+
+use super::{
+    app::ExternalAppCourse,
+    locale::{fill, Strings},
+};
+
+/// The free exercises on musictheory.net, whose lessons are presets of the exercises.
+pub struct MusicTheoryNet;
+
+impl ExternalAppCourse for MusicTheoryNet {
+    fn app_name(&self) -> &str {
+        "musictheory.net"
+    }
+
+    fn app_version(&self) -> Option<String> {
+        None
+    }
+
+    fn exercise_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        &strings.musictheory_exercise_front
+    }
+
+    fn instructions_template<'a>(&'a self, strings: &'a Strings) -> &'a str {
+        &strings.musictheory_course_instructions
+    }
+
+    fn metadata_key(&self) -> &str {
+        "app"
+    }
+
+    fn metadata_value(&self) -> String {
+        "musictheory.net".to_string()
+    }
+}
+
+/// The exercises on musictheory.net.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Exercise {
+    /// Naming the notes written on the staff.
+    NoteIdentification,
+
+    /// Naming the key of the key signatures written on the staff.
+    KeySignatureIdentification,
+
+    /// Writing the key signature of the given key.
+    KeySignatureConstruction,
+
+    /// Naming the intervals written on the staff.
+    IntervalIdentification,
+
+    /// Writing the interval above the given note.
+    IntervalConstruction,
+
+    /// Naming the chords written on the staff.
+    ChordIdentification,
+
+    /// Writing the chord built on the given note.
+    ChordConstruction,
+
+    /// Naming the intervals played by the app.
+    IntervalEarTrainer,
+
+    /// Naming the chords played by the app.
+    ChordEarTrainer,
+
+    /// Naming the scales played by the app.
+    ScaleEarTrainer,
+}
+
+impl fmt::Display for Exercise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Exercise::NoteIdentification => "Note Identification",
+            Exercise::KeySignatureIdentification => "Key Signature Identification",
+            Exercise::KeySignatureConstruction => "Key Signature Construction",
+            Exercise::IntervalIdentification => "Interval Identification",
+            Exercise::IntervalConstruction => "Interval Construction",
+            Exercise::ChordIdentification => "Chord Identification",
+            Exercise::ChordConstruction => "Chord Construction",
+            Exercise::IntervalEarTrainer => "Interval Ear Trainer",
+            Exercise::ChordEarTrainer => "Chord Ear Trainer",
+            Exercise::ScaleEarTrainer => "Scale Ear Trainer",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The exercise practiced in a lesson along with the settings selected on its settings page.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Preset {
+    /// The exercise to open.
+    pub exercise: Exercise,
+
+    /// The settings to select, mapped to their values, such as "Intervals" mapped to "m2, M2, m3".
+    #[serde(default)]
+    pub settings: BTreeMap<String, String>,
+}
+
+impl Preset {
+    /// Returns the description of the preset written on the front of the exercise cards.
+    pub fn contents(&self, strings: &Strings) -> String {
+        let intro = fill(
+            &strings.musictheory_preset,
+            &[("exercise", &self.exercise.to_string())],
+        );
+        let mut contents = format!("\n{}\n\n", intro);
+        for (setting, value) in &self.settings {
+            contents.push_str(&format!("- {}: {}\n", setting, value));
+        }
+        contents
+    }
+
+    /// Returns the problems with the preset.
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = vec![];
+        if self.settings.is_empty() {
+            issues.push(format!(
+                "the preset of the {} has no settings",
+                self.exercise
+            ));
+        }
+        for (setting, value) in &self.settings {
+            if value.trim().is_empty() {
+                issues.push(format!("setting {} of the preset has no value", setting));
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use indoc::indoc;
+
+    use super::{Exercise, Preset};
+    use crate::earmaster::{catalog::bundled_catalogs, locale::Strings, validation::find_issues};
+
+    /// Verifies the description of a preset on the exercise cards.
+    #[test]
+    fn preset_contents() {
+        let preset = Preset {
+            exercise: Exercise::IntervalEarTrainer,
+            settings: BTreeMap::from([
+                ("Intervals".to_string(), "m2, M2, m3".to_string()),
+                ("Direction".to_string(), "Ascending".to_string()),
+            ]),
+        };
+        assert!(preset.issues().is_empty());
+        assert_eq!(
+            preset.contents(&Strings::default()),
+            indoc! {"
+
+                Open the Interval Ear Trainer exercise and select the following settings before starting:
+
+                - Direction: Ascending
+                - Intervals: m2, M2, m3
+            "}
+        );
+    }
+
+    /// Verifies that every musictheory.net lesson has a preset and that the courses depend on the
+    /// matching EarMaster courses.
+    #[test]
+    fn bundled_courses_have_presets() -> anyhow::Result<()> {
+        let courses = bundled_catalogs()?;
+        assert!(find_issues(&courses).is_empty());
+        let musictheory: Vec<_> = courses
+            .iter()
+            .filter(|course| course.is_musictheory())
+            .collect();
+        assert!(!musictheory.is_empty());
+        for course in &musictheory {
+            assert!(course.lessons.iter().all(|lesson| lesson.preset.is_some()));
+        }
+        let ear_trainer = musictheory
+            .iter()
+            .find(|course| course.directory_name == "musictheory_interval_ear_trainer")
+            .unwrap();
+        assert!(ear_trainer
+            .dependencies
+            .contains(&"trane::music::earmaster::interval_identification".into()));
+        Ok(())
+    }
+}
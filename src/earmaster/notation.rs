@@ -0,0 +1,172 @@
+//! Contains the notations in which the scale degrees in the lesson names are written. The catalogs
+//! describe the degrees with movable-do syllables, but students taught with fixed do or scale
+//! numbers read the names more easily in the notation they use, so the notation is selected at
+//! build time. Only the names change, so the IDs of the lessons are the same in every notation.
+use anyhow::Result;
+use clap::ValueEnum;
+
+use super::{descriptor::Syllable, EarMasterCourse};
+
+/// The number of semitones between C and each of the natural notes, starting from C.
+const NATURAL_SEMITONES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// The letter names of the natural notes, starting from C.
+const LETTER_NAMES: [&str; 7] = ["C", "D", "E", "F", "G", "A", "B"];
+
+/// The fixed-do names of the natural notes, starting from C.
+const FIXED_DO_NAMES: [&str; 7] = ["Do", "Re", "Mi", "Fa", "Sol", "La", "Si"];
+
+/// The notations in which the scale degrees can be written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Notation {
+    /// Movable-do syllables, in which Do is always the tonic, as in EarMaster.
+    #[default]
+    #[value(name = "movable-do")]
+    MovableDo,
+
+    /// Movable-do syllables, except that La is the tonic in minor keys.
+    #[value(name = "la-based-minor")]
+    LaBasedMinor,
+
+    /// Fixed-do syllables, in which Do is always C. Lessons in more than one key are written as if
+    /// they were in C.
+    #[value(name = "fixed-do")]
+    FixedDo,
+
+    /// Scale degree numbers, such as 1, 2, and b3.
+    #[value(name = "numbers")]
+    ScaleNumbers,
+
+    /// Letter names, as if every lesson were in C.
+    #[value(name = "letters")]
+    LettersInC,
+}
+
+/// Returns the accidentals that raise or lower a note by the given number of semitones.
+fn accidentals(semitones: i16) -> String {
+    let accidental = if semitones > 0 { "#" } else { "b" };
+    accidental.repeat(semitones.unsigned_abs() as usize)
+}
+
+/// Returns the number of semitones by which the note with the given diatonic step and pitch class
+/// differs from the natural note on that step, between -6 and 5.
+fn alteration(step: usize, pitch_class: i16) -> i16 {
+    let difference = (pitch_class - NATURAL_SEMITONES[step] as i16).rem_euclid(12);
+    if difference > 6 {
+        difference - 12
+    } else {
+        difference
+    }
+}
+
+/// Parses a key such as "C", "F#", or "Bb" into the step of its letter and its pitch class.
+fn parse_key(key: &str) -> Option<(usize, i16)> {
+    let mut chars = key.chars();
+    let letter = chars.next()?.to_ascii_uppercase();
+    let step = LETTER_NAMES
+        .iter()
+        .position(|name| name.starts_with(letter))?;
+    let mut pitch_class = NATURAL_SEMITONES[step] as i16;
+    for accidental in chars {
+        match accidental {
+            '#' => pitch_class += 1,
+            'b' => pitch_class -= 1,
+            _ => return None,
+        }
+    }
+    Some((step, pitch_class))
+}
+
+/// Returns the name of the note at the given degree of the key with the given tonic, spelled with
+/// the given names for the natural notes.
+fn spell(degree: Syllable, tonic: (usize, i16), names: &[&str; 7]) -> String {
+    let step = (tonic.0 + degree.step()) % 7;
+    let pitch_class = tonic.1 + degree.semitones() as i16;
+    format!(
+        "{}{}",
+        names[step],
+        accidentals(alteration(step, pitch_class))
+    )
+}
+
+impl Notation {
+    /// Returns the name of the scale degree in a lesson with the given key and mode.
+    pub fn degree_name(&self, degree: Syllable, key: Option<&str>, mode: Option<&str>) -> String {
+        match self {
+            Notation::MovableDo => degree.name().to_string(),
+            Notation::LaBasedMinor => {
+                let is_minor = mode.is_some_and(|mode| mode.contains("minor"));
+                let relative =
+                    Syllable::from_position((degree.step() + 5) % 7, (degree.semitones() + 9) % 12);
+                match relative {
+                    Some(relative) if is_minor => relative.name().to_string(),
+                    _ => degree.name().to_string(),
+                }
+            }
+            Notation::FixedDo => {
+                let tonic = key.and_then(parse_key).unwrap_or((0, 0));
+                spell(degree, tonic, &FIXED_DO_NAMES)
+            }
+            Notation::ScaleNumbers => {
+                let alteration = alteration(degree.step(), degree.semitones() as i16);
+                format!("{}{}", accidentals(alteration), degree.step() + 1)
+            }
+            Notation::LettersInC => spell(degree, (0, 0), &LETTER_NAMES),
+        }
+    }
+}
+
+/// Returns the given courses with the scale degrees in their lesson names written in the given
+/// notation.
+pub fn courses_with_notation(
+    courses: &[EarMasterCourse],
+    notation: Notation,
+) -> Result<Vec<EarMasterCourse>> {
+    courses
+        .iter()
+        .map(|course| {
+            let mut course = course.clone();
+            course.notation = notation;
+            course.render_lesson_names()?;
+            Ok(course)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Notation;
+    use crate::earmaster::descriptor::Syllable;
+
+    /// Verifies the names of the degrees in every notation.
+    #[test]
+    fn degree_names() {
+        let names = |notation: Notation, key: Option<&str>, mode: Option<&str>| {
+            [
+                Syllable::Do,
+                Syllable::Re,
+                Syllable::Me,
+                Syllable::Fi,
+                Syllable::Ti,
+            ]
+            .into_iter()
+            .map(|degree| notation.degree_name(degree, key, mode))
+            .collect::<Vec<_>>()
+            .join(" ")
+        };
+        let minor = Some("harmonic_minor");
+        assert_eq!(names(Notation::MovableDo, None, minor), "Do Re Me Fi Ti");
+        assert_eq!(names(Notation::LaBasedMinor, None, minor), "La Ti Do Ri Si");
+        assert_eq!(names(Notation::LaBasedMinor, None, None), "Do Re Me Fi Ti");
+        assert_eq!(
+            names(Notation::FixedDo, Some("A"), minor),
+            "La Si Do Re# Sol#"
+        );
+        assert_eq!(
+            names(Notation::FixedDo, Some("Bb"), None),
+            "Sib Do Reb Mi La"
+        );
+        assert_eq!(names(Notation::ScaleNumbers, None, None), "1 2 b3 #4 7");
+        assert_eq!(names(Notation::LettersInC, Some("G"), None), "C D Eb F# B");
+    }
+}
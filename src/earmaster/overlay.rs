@@ -0,0 +1,381 @@
+//! Contains the overlays that adapt the catalogs to a particular school or teacher. EarMaster lets
+//! teachers create customized exercises, which fit between the stock lessons of an activity, so an
+//! overlay can add lessons to a course, change the name or dependencies of its lessons, and hide
+//! the lessons that are not used. Overlays are kept in local files and merged into the courses
+//! before anything else, so the rest of the build treats the added lessons like any other.
+//! Overlays reference the lessons of the catalogs by ID, so they are checked against the catalogs
+//! every time they are merged, which catches the lessons that were removed or renumbered since the
+//! overlay was written.
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use ustr::Ustr;
+
+use super::{
+    reference::{find_course, is_qualified, resolve_references},
+    EarMasterCourse, EarMasterLesson, EarMasterUnit,
+};
+
+/// The changes to a stock lesson.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct LessonOverride {
+    /// The new name of the lesson, which can use the same placeholders as the catalogs.
+    pub name: Option<String>,
+
+    /// The new dependencies of the lesson, which replace the ones in the catalog.
+    pub dependencies: Option<Vec<Ustr>>,
+}
+
+/// The changes to a course.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CourseOverlay {
+    /// The units added to the course, such as a unit grouping the customized exercises.
+    pub units: Vec<EarMasterUnit>,
+
+    /// The lessons added to the course, written as in the catalogs. Their IDs must not be used by
+    /// the stock lessons, so IDs such as `custom.3` are recommended.
+    pub lessons: Vec<EarMasterLesson>,
+
+    /// The changes to the stock lessons, indexed by lesson ID.
+    pub overrides: BTreeMap<Ustr, LessonOverride>,
+
+    /// The lessons to hide. The lessons that depend on them depend on their dependencies instead.
+    pub hidden: Vec<Ustr>,
+}
+
+/// A set of changes to the courses described by the catalogs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Overlay {
+    /// The changes to each course, indexed by course ID or directory name.
+    #[serde(default)]
+    pub courses: BTreeMap<String, CourseOverlay>,
+
+    /// The name of the file from which the overlay was loaded. Used to give context to errors.
+    #[serde(skip)]
+    pub source: String,
+}
+
+impl Overlay {
+    /// Returns the problems with the overlay, such as references to lessons that are no longer in
+    /// the catalogs or added lessons whose IDs are already used.
+    pub fn issues(&self, courses: &[EarMasterCourse]) -> Vec<String> {
+        let mut issues = vec![];
+        for (name, overlay) in &self.courses {
+            let Some(course) = find_course(courses, name) else {
+                issues.push(format!("{}: unknown course {}", self.source, name));
+                continue;
+            };
+            let is_stock = |id: &Ustr| course.lessons.iter().any(|lesson| lesson.id == *id);
+            let is_added = |id: &Ustr| overlay.lessons.iter().any(|lesson| lesson.id == *id);
+
+            for unit in &overlay.units {
+                if course.units.iter().any(|known| known.id == unit.id) {
+                    issues.push(format!(
+                        "{}: course {} already has a unit {}",
+                        self.source, name, unit.id
+                    ));
+                }
+            }
+            for (index, lesson) in overlay.lessons.iter().enumerate() {
+                if is_stock(&lesson.id) {
+                    issues.push(format!(
+                        "{}: course {} already has a lesson {}, which can be changed in overrides",
+                        self.source, name, lesson.id
+                    ));
+                } else if overlay.lessons[..index]
+                    .iter()
+                    .any(|other| other.id == lesson.id)
+                {
+                    issues.push(format!(
+                        "{}: course {} has more than one added lesson {}",
+                        self.source, name, lesson.id
+                    ));
+                }
+            }
+            for id in overlay.overrides.keys() {
+                if !is_stock(id) {
+                    issues.push(format!(
+                        "{}: course {} has no lesson {} to override, it might have been removed \
+                        or renumbered in the catalog",
+                        self.source, name, id
+                    ));
+                }
+            }
+            for id in &overlay.hidden {
+                if !is_stock(id) {
+                    issues.push(format!(
+                        "{}: course {} has no lesson {} to hide, it might have been removed or \
+                        renumbered in the catalog",
+                        self.source, name, id
+                    ));
+                }
+            }
+
+            // The dependencies on the lessons of other courses are checked with the merged courses.
+            let dependencies = overlay
+                .lessons
+                .iter()
+                .map(|lesson| (lesson.id, &lesson.dependencies))
+                .chain(overlay.overrides.iter().filter_map(|(id, lesson)| {
+                    lesson
+                        .dependencies
+                        .as_ref()
+                        .map(|dependencies| (*id, dependencies))
+                }));
+            for (id, dependencies) in dependencies {
+                for dependency in dependencies.iter().filter(|d| !is_qualified(d)) {
+                    if !is_stock(dependency) && !is_added(dependency) {
+                        issues.push(format!(
+                            "{}: lesson {} of course {} depends on unknown lesson {}",
+                            self.source, id, name, dependency
+                        ));
+                    }
+                }
+            }
+        }
+        issues
+    }
+
+    /// Returns the course with the changes in the overlay. The lessons are added and changed
+    /// before the hidden lessons are removed, so that the dependencies on the hidden lessons are
+    /// rewritten in the added lessons too.
+    pub fn apply(&self, course: &EarMasterCourse) -> Result<EarMasterCourse> {
+        let mut merged = course.clone();
+        let Some(overlay) = self
+            .courses
+            .iter()
+            .find(|(name, _)| course.id == name.as_str() || course.directory_name == **name)
+            .map(|(_, overlay)| overlay)
+        else {
+            return Ok(merged);
+        };
+
+        merged.units.extend(overlay.units.iter().cloned());
+        merged.lessons.extend(overlay.lessons.iter().cloned());
+        for lesson in &mut merged.lessons {
+            let Some(changes) = overlay.overrides.get(&lesson.id) else {
+                continue;
+            };
+            if let Some(name) = &changes.name {
+                lesson.rename(name, merged.notation, &merged.strings);
+            }
+            if let Some(dependencies) = &changes.dependencies {
+                lesson.dependencies = dependencies.clone();
+            }
+        }
+        merged
+            .render_lesson_names()
+            .with_context(|| format!("failed to apply overlay {}", self.source))?;
+        let hidden: Vec<EarMasterLesson> = merged
+            .lessons
+            .iter()
+            .filter(|lesson| overlay.hidden.contains(&lesson.id))
+            .cloned()
+            .collect();
+        merged.retain_lessons(|lesson| !overlay.hidden.contains(&lesson.id));
+        merged.hidden_lessons.extend(hidden);
+        Ok(merged)
+    }
+}
+
+/// Returns the courses with the changes in the given overlays, which are applied in order. Every
+/// overlay is checked against the courses before it is applied. Once all the overlays are applied,
+/// the dependencies on the lessons of other courses are resolved, so that the dependencies on the
+/// hidden lessons are replaced with the dependencies of those lessons, as it is done within a
+/// course.
+pub fn apply_overlays(
+    courses: &[EarMasterCourse],
+    overlays: &[Overlay],
+) -> Result<Vec<EarMasterCourse>> {
+    if overlays.is_empty() {
+        return Ok(courses.to_vec());
+    }
+    let mut merged = courses.to_vec();
+    for overlay in overlays {
+        let issues = overlay.issues(&merged);
+        if !issues.is_empty() {
+            bail!(
+                "found {} issue(s) in the overlay {}:\n{}",
+                issues.len(),
+                overlay.source,
+                issues.join("\n")
+            );
+        }
+        merged = merged
+            .iter()
+            .map(|course| overlay.apply(course))
+            .collect::<Result<_>>()?;
+    }
+
+    // Resolve the references with the hidden lessons put back, so that their dependencies are
+    // known.
+    let unhidden: Vec<EarMasterCourse> = merged
+        .iter()
+        .map(|course| {
+            let mut course = course.clone();
+            course.lessons.extend(course.hidden_lessons.iter().cloned());
+            course
+        })
+        .collect();
+    Ok(resolve_references(&unhidden, &merged))
+}
+
+/// Parses the contents of an overlay. The file name is used to give context to any errors.
+pub fn parse_overlay(file_name: &str, contents: &str) -> Result<Overlay> {
+    let mut overlay: Overlay = toml::from_str(contents)
+        .with_context(|| format!("failed to parse overlay {}", file_name))?;
+    overlay.source = file_name.to_string();
+    Ok(overlay)
+}
+
+/// Reads and parses the overlay at the given path.
+pub fn load_overlay(path: &Path) -> Result<Overlay> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read overlay {}", path.display()))?;
+    parse_overlay(&path.display().to_string(), &contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::Ustr;
+
+    use super::{apply_overlays, parse_overlay};
+    use crate::earmaster::{catalog::parse_catalog, validation::find_issues, EarMasterCourse};
+
+    /// Returns the course to which the overlays in the tests are applied.
+    fn course() -> EarMasterCourse {
+        parse_catalog(
+            "melody.toml",
+            r#"
+                id = "trane::test::melody"
+                name = "Melody"
+                directory_name = "melody"
+                units = [{ id = "1", title = "First" }]
+                lessons = [
+                    { id = "1.1", name = "A" },
+                    { id = "1.2", name = "B", dependencies = ["1.1"] },
+                    { id = "1.3", name = "C", dependencies = ["1.2"] },
+                ]
+            "#,
+        )
+        .unwrap()
+    }
+
+    /// Verifies that an overlay adds, changes, and hides lessons.
+    #[test]
+    fn applies_overlay() -> anyhow::Result<()> {
+        let overlay = parse_overlay(
+            "school.toml",
+            r#"
+                [courses.melody]
+                units = [{ id = "custom", title = "Customized exercises" }]
+                lessons = [{ id = "custom.3", name = "Custom", dependencies = ["1.2"] }]
+                hidden = ["1.2"]
+
+                [courses.melody.overrides]
+                "1.3" = { name = "Renamed", dependencies = ["custom.3"] }
+            "#,
+        )?;
+        let courses = apply_overlays(&[course()], &[overlay])?;
+        assert!(find_issues(&courses).is_empty());
+        let lessons: Vec<(&str, &str, Vec<Ustr>)> = courses[0]
+            .lessons
+            .iter()
+            .map(|lesson| {
+                (
+                    lesson.id.as_str(),
+                    lesson.name.as_str(),
+                    lesson.dependencies.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            lessons,
+            vec![
+                ("1.1", "A", vec![]),
+                ("1.3", "Renamed", vec![Ustr::from("custom.3")]),
+                ("custom.3", "Custom", vec![Ustr::from("1.1")]),
+            ]
+        );
+        assert_eq!(courses[0].units.len(), 2);
+        assert_eq!(courses[0].hidden_lessons.len(), 1);
+        assert_eq!(courses[0].hidden_lessons[0].id, "1.2");
+        Ok(())
+    }
+
+    /// Verifies that the lessons of other courses that depend on a hidden lesson depend on the
+    /// dependencies of the hidden lesson instead.
+    #[test]
+    fn hides_lessons_of_other_courses() -> anyhow::Result<()> {
+        let dictation = parse_catalog(
+            "dictation.toml",
+            r#"
+                id = "trane::test::dictation"
+                name = "Dictation"
+                directory_name = "dictation"
+                lessons = [
+                    { id = "1.1", name = "D", dependencies = ["melody::1.3"] },
+                    { id = "1.2", name = "E", dependencies = ["1.1", "melody::1.1"] },
+                ]
+            "#,
+        )?;
+        let overlay = parse_overlay(
+            "school.toml",
+            r#"
+                [courses.melody]
+                hidden = ["1.2", "1.3"]
+            "#,
+        )?;
+        let courses = apply_overlays(&[course(), dictation], &[overlay])?;
+        assert!(find_issues(&courses).is_empty());
+        let dependencies: Vec<Vec<Ustr>> = courses[1]
+            .lessons
+            .iter()
+            .map(|lesson| lesson.dependencies.clone())
+            .collect();
+        assert_eq!(
+            dependencies,
+            vec![
+                vec![Ustr::from("trane::test::melody::1.1")],
+                vec![Ustr::from("1.1"), Ustr::from("trane::test::melody::1.1")],
+            ]
+        );
+        Ok(())
+    }
+
+    /// Verifies that an overlay referencing lessons that are not in the catalog is rejected.
+    #[test]
+    fn rejects_unknown_lessons() -> anyhow::Result<()> {
+        let overlay = parse_overlay(
+            "school.toml",
+            r#"
+                [courses.melody]
+                lessons = [{ id = "1.1", name = "Again" }, { id = "custom.1", name = "D", dependencies = ["2.1"] }]
+                hidden = ["1.4"]
+
+                [courses.melody.overrides]
+                "1.9" = { name = "Renamed" }
+
+                [courses.harmony]
+                hidden = ["1.1"]
+            "#,
+        )?;
+        let error = apply_overlays(&[course()], &[overlay]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "found 5 issue(s) in the overlay school.toml:\n\
+            school.toml: unknown course harmony\n\
+            school.toml: course melody already has a lesson 1.1, which can be changed in \
+            overrides\n\
+            school.toml: course melody has no lesson 1.9 to override, it might have been removed \
+            or renumbered in the catalog\n\
+            school.toml: course melody has no lesson 1.4 to hide, it might have been removed or \
+            renumbered in the catalog\n\
+            school.toml: lesson custom.1 of course melody depends on unknown lesson 2.1"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+//! Contains the parallel lessons of different activities. Some activities practice the same
+//! material in the same order, such as the first units of Melody Singback, Melody Sight-Singing,
+//! and Melody Dictation, or the units of the rhythm activities. A catalog declares the courses
+//! whose lessons parallel its own in its `parallel` array, and two lessons are parallel if they
+//! have the same ID and describe the same material. The number of bars is not compared, since the
+//! exercises of some activities are longer than those of others.
+//!
+//! Parallel lessons can be turned into dependencies, so that each lesson waits for the parallel
+//! lesson in the earlier activity instead of the whole course, and their names can be compared to
+//! find the ones that drifted apart.
+use std::fmt::Write;
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+use super::{
+    descriptor::LessonDescriptor,
+    reference::{find_course, is_qualified},
+    EarMasterCourse, EarMasterLesson,
+};
+
+/// A course whose lessons parallel the lessons of the course in whose catalog it is declared.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ParallelCourse {
+    /// The directory name or ID of the course.
+    pub course: String,
+
+    /// The units in which the lessons are parallel, or all the units if empty.
+    #[serde(default)]
+    pub units: Vec<String>,
+}
+
+/// A lesson along with the parallel lesson in another course.
+pub struct ParallelLesson<'a> {
+    /// The lesson.
+    pub lesson: &'a EarMasterLesson,
+
+    /// The course whose lesson parallels the lesson.
+    pub other_course: &'a EarMasterCourse,
+
+    /// The parallel lesson in the other course.
+    pub other_lesson: &'a EarMasterLesson,
+}
+
+impl ParallelLesson<'_> {
+    /// Returns whether the names of the parallel lessons differ. Names generated from the
+    /// descriptors of both lessons are not compared, since they can only differ in the number of
+    /// bars, and neither are the names of lessons that only differ in the value of their `{bars}`
+    /// placeholders.
+    pub fn has_drifted(&self) -> bool {
+        match (&self.lesson.name_template, &self.other_lesson.name_template) {
+            (None, None) => false,
+            (Some(template), Some(other)) if template.contains("{bars}") => template != other,
+            _ => self.lesson.name != self.other_lesson.name,
+        }
+    }
+}
+
+/// Returns whether the descriptors describe the same material, regardless of the number of bars.
+/// Lessons without a descriptor never describe the same material.
+fn same_material(descriptor: &LessonDescriptor, other: &LessonDescriptor) -> bool {
+    let without_bars = |descriptor: &LessonDescriptor| LessonDescriptor {
+        bars: None,
+        ..descriptor.clone()
+    };
+    *descriptor != LessonDescriptor::default() && without_bars(descriptor) == without_bars(other)
+}
+
+/// Returns the lessons of the given course which are parallel to the lessons of the courses
+/// declared in its catalog. Unknown courses are ignored, since they are reported by validation.
+pub fn parallel_lessons<'a>(
+    courses: &'a [EarMasterCourse],
+    course: &'a EarMasterCourse,
+) -> Vec<ParallelLesson<'a>> {
+    let mut parallel = vec![];
+    for declared in &course.parallel {
+        let Some(other_course) = find_course(courses, &declared.course) else {
+            continue;
+        };
+        for lesson in &course.lessons {
+            if !declared.units.is_empty() && !declared.units.iter().any(|u| u == lesson.unit_id()) {
+                continue;
+            }
+            if let Some(other_lesson) = other_course
+                .lessons
+                .iter()
+                .find(|other| other.id == lesson.id)
+                .filter(|other| same_material(&lesson.descriptor, &other.descriptor))
+            {
+                parallel.push(ParallelLesson {
+                    lesson,
+                    other_course,
+                    other_lesson,
+                });
+            }
+        }
+    }
+    parallel
+}
+
+/// Returns the given courses with a dependency from every lesson on its parallel lessons. The
+/// dependency of a course on a parallel course is removed if every lesson that does not depend on
+/// another lesson of the course now depends on a lesson of the parallel course, since the lesson
+/// dependencies then replace it.
+pub fn link_parallel_lessons(courses: &[EarMasterCourse]) -> Vec<EarMasterCourse> {
+    courses
+        .iter()
+        .map(|course| {
+            let parallel = parallel_lessons(courses, course);
+            let mut linked = course.clone();
+            for lesson in &mut linked.lessons {
+                for other in parallel.iter().filter(|other| other.lesson.id == lesson.id) {
+                    lesson
+                        .dependencies
+                        .push(other.other_lesson.full_id(&other.other_course.id));
+                }
+            }
+
+            let first_lessons: Vec<&EarMasterLesson> = course
+                .lessons
+                .iter()
+                .filter(|lesson| lesson.dependencies.iter().all(is_qualified))
+                .collect();
+            let is_replaced = |dependency: &Ustr| {
+                !first_lessons.is_empty()
+                    && first_lessons.iter().all(|lesson| {
+                        parallel.iter().any(|other| {
+                            other.lesson.id == lesson.id && other.other_course.id == *dependency
+                        })
+                    })
+            };
+            linked
+                .dependencies
+                .retain(|dependency| !is_replaced(dependency));
+            linked
+        })
+        .collect()
+}
+
+/// Returns a report listing, for every course with parallel courses, the number of parallel
+/// lessons and the parallel lessons whose names have drifted apart.
+pub fn drift_report(courses: &[EarMasterCourse]) -> String {
+    let mut report = String::new();
+    let mut drifted_count = 0;
+    for course in courses {
+        let parallel = parallel_lessons(courses, course);
+        let mut other_courses: Vec<Ustr> = vec![];
+        for other in &parallel {
+            if !other_courses.contains(&other.other_course.id) {
+                other_courses.push(other.other_course.id);
+            }
+        }
+
+        for other_course in other_courses {
+            let lessons: Vec<&ParallelLesson> = parallel
+                .iter()
+                .filter(|other| other.other_course.id == other_course)
+                .collect();
+            writeln!(
+                report,
+                "{} - {} lessons parallel to {}",
+                course.id,
+                lessons.len(),
+                other_course
+            )
+            .unwrap();
+            for other in lessons {
+                if other.has_drifted() {
+                    drifted_count += 1;
+                    writeln!(
+                        report,
+                        "    lesson {}: \"{}\" differs from \"{}\"",
+                        other.lesson.id, other.lesson.name, other.other_lesson.name
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+    writeln!(
+        report,
+        "{} parallel lesson(s) with drifted names",
+        drifted_count
+    )
+    .unwrap();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::Ustr;
+
+    use super::{drift_report, link_parallel_lessons, parallel_lessons};
+    use crate::earmaster::{
+        catalog::{bundled_catalogs, parse_catalog},
+        validation::find_issues,
+        EarMasterCourse,
+    };
+
+    /// Returns the courses used in the tests, in which dictation parallels the first unit of
+    /// sight-singing.
+    fn courses() -> Vec<EarMasterCourse> {
+        let sight_singing = parse_catalog(
+            "sight_singing.toml",
+            r#"
+                id = "trane::test::sight_singing"
+                name = "Sight-Singing"
+                directory_name = "sight_singing"
+                lessons = [
+                    { id = "1.1", bars = 4, tones = 3, degrees = ["do", "re"] },
+                    { id = "1.2", name = "Do to Mi", dependencies = ["1.1"], tones = 3, degrees = ["do", "re", "mi"] },
+                    { id = "2.1", dependencies = ["1.2"], tones = 5, degrees = ["do", "re"] },
+                ]
+            "#,
+        )
+        .unwrap();
+        let dictation = parse_catalog(
+            "dictation.toml",
+            r#"
+                id = "trane::test::dictation"
+                name = "Dictation"
+                directory_name = "dictation"
+                dependencies = ["trane::test::sight_singing"]
+                parallel = [{ course = "sight_singing", units = ["1"] }]
+                lessons = [
+                    { id = "1.1", bars = 1, tones = 3, degrees = ["do", "re"] },
+                    { id = "1.2", name = "Do, Re, Mi", dependencies = ["1.1"], tones = 3, degrees = ["do", "re", "mi"] },
+                    { id = "2.1", dependencies = ["1.2"], tones = 5, degrees = ["do", "re"] },
+                ]
+            "#,
+        )
+        .unwrap();
+        vec![sight_singing, dictation]
+    }
+
+    /// Verifies that the lessons with the same ID and material in the declared units are parallel,
+    /// regardless of the number of bars.
+    #[test]
+    fn finds_parallel_lessons() {
+        let courses = courses();
+        assert!(find_issues(&courses).is_empty());
+        let parallel: Vec<&str> = parallel_lessons(&courses, &courses[1])
+            .iter()
+            .map(|lesson| lesson.lesson.id.as_str())
+            .collect();
+        assert_eq!(parallel, vec!["1.1", "1.2"]);
+        assert!(parallel_lessons(&courses, &courses[0]).is_empty());
+    }
+
+    /// Verifies the issues with the declared parallel courses.
+    #[test]
+    fn reports_invalid_parallel_courses() {
+        let mut courses = courses();
+        courses[1].parallel[0].units.push("3".to_string());
+        courses[0].parallel = courses[1].parallel.clone();
+        courses[0].parallel[0].units.clear();
+        courses[0].parallel[0].course = "missing".to_string();
+        let messages: Vec<String> = find_issues(&courses)
+            .into_iter()
+            .map(|issue| issue.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "unknown parallel course missing".to_string(),
+                "unknown unit 3 in parallel course sight_singing".to_string(),
+            ]
+        );
+    }
+
+    /// Verifies that the parallel lessons replace the dependency on the parallel course.
+    #[test]
+    fn links_parallel_lessons() {
+        let linked = link_parallel_lessons(&courses());
+        assert!(linked[1].dependencies.is_empty());
+        let dependencies: Vec<Vec<Ustr>> = linked[1]
+            .lessons
+            .iter()
+            .map(|lesson| lesson.dependencies.clone())
+            .collect();
+        assert_eq!(
+            dependencies,
+            vec![
+                vec![Ustr::from("trane::test::sight_singing::1.1")],
+                vec![
+                    Ustr::from("1.1"),
+                    Ustr::from("trane::test::sight_singing::1.2")
+                ],
+                vec![Ustr::from("1.2")],
+            ]
+        );
+        assert!(find_issues(&linked).is_empty());
+    }
+
+    /// Verifies that the report lists the parallel lessons whose names have drifted apart, but not
+    /// the generated names that only differ in the number of bars.
+    #[test]
+    fn reports_drifted_names() {
+        assert_eq!(
+            drift_report(&courses()),
+            "trane::test::dictation - 2 lessons parallel to trane::test::sight_singing\n    \
+            lesson 1.2: \"Do, Re, Mi\" differs from \"Do to Mi\"\n\
+            1 parallel lesson(s) with drifted names\n"
+        );
+    }
+
+    /// Verifies that the names of the parallel lessons in the bundled catalogs have not drifted
+    /// apart.
+    #[test]
+    fn bundled_catalogs_have_no_drifted_names() -> anyhow::Result<()> {
+        let report = drift_report(&bundled_catalogs()?);
+        assert!(
+            report.ends_with("\n0 parallel lesson(s) with drifted names\n"),
+            "{}",
+            report
+        );
+        Ok(())
+    }
+}
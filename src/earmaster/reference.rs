@@ -0,0 +1,201 @@
+//! Contains the dependencies of lessons on the lessons of other courses. A lesson dependency is
+//! usually the short ID of another lesson in the same course, but it can also be qualified with a
+//! course, as in `rhythm_dictation::1.8`, where the course is given by its directory name or its
+//! ID. Qualified dependencies are resolved to full lesson IDs once the lessons of every course in
+//! the selected release and editions are known.
+use std::collections::{HashMap, HashSet};
+
+use ustr::Ustr;
+
+use super::{rewrite_dependencies, EarMasterCourse};
+
+/// The separator between the course and the lesson in a qualified dependency.
+const SEPARATOR: &str = "::";
+
+/// Splits a qualified dependency into its course and lesson, or returns `None` if the dependency
+/// refers to a lesson in the same course.
+pub fn parse_reference(dependency: &str) -> Option<(&str, &str)> {
+    dependency.rsplit_once(SEPARATOR)
+}
+
+/// Returns whether the dependency refers to a lesson in another course.
+pub fn is_qualified(dependency: &Ustr) -> bool {
+    dependency.contains(SEPARATOR)
+}
+
+/// Returns the course with the given directory name or ID.
+pub fn find_course<'a>(
+    courses: &'a [EarMasterCourse],
+    course: &str,
+) -> Option<&'a EarMasterCourse> {
+    courses
+        .iter()
+        .find(|other| other.directory_name == course || other.id == course)
+}
+
+/// Returns the full ID of the lesson to which the given qualified dependency refers, or `None` if
+/// the dependency is not qualified or names an unknown course.
+pub fn qualified_lesson_id(courses: &[EarMasterCourse], dependency: &str) -> Option<Ustr> {
+    let (course, lesson) = parse_reference(dependency)?;
+    let course = find_course(courses, course)?;
+    Some(Ustr::from(&format!("{}{}{}", course.id, SEPARATOR, lesson)))
+}
+
+/// Returns the full ID of the lesson to which the given dependency of a lesson in the given course
+/// refers, or `None` if the dependency names an unknown course.
+pub fn full_dependency_id(
+    courses: &[EarMasterCourse],
+    course: &EarMasterCourse,
+    dependency: &Ustr,
+) -> Option<Ustr> {
+    if is_qualified(dependency) {
+        qualified_lesson_id(courses, dependency)
+    } else {
+        Some(Ustr::from(&format!(
+            "{}{}{}",
+            course.id, SEPARATOR, dependency
+        )))
+    }
+}
+
+/// Resolves the qualified dependencies of the lessons in the given courses to full lesson IDs. The
+/// catalogs are the courses before any lessons were left out, and are used to replace the
+/// dependencies on lessons that are not in the courses with the dependencies of those lessons, as
+/// it is done for the dependencies within a course.
+pub fn resolve_references(
+    catalogs: &[EarMasterCourse],
+    courses: &[EarMasterCourse],
+) -> Vec<EarMasterCourse> {
+    let catalog_dependencies: HashMap<Ustr, Vec<Ustr>> = catalogs
+        .iter()
+        .flat_map(|course| {
+            course.lessons.iter().map(move |lesson| {
+                let dependencies = lesson
+                    .dependencies
+                    .iter()
+                    .filter_map(|dependency| full_dependency_id(catalogs, course, dependency))
+                    .collect();
+                (lesson.full_id(&course.id), dependencies)
+            })
+        })
+        .collect();
+    let all_dependencies: HashMap<Ustr, &[Ustr]> = catalog_dependencies
+        .iter()
+        .map(|(id, dependencies)| (*id, dependencies.as_slice()))
+        .collect();
+    let included: HashSet<Ustr> = courses
+        .iter()
+        .flat_map(|course| {
+            course
+                .lessons
+                .iter()
+                .map(|lesson| lesson.full_id(&course.id))
+        })
+        .collect();
+
+    courses
+        .iter()
+        .map(|course| {
+            let mut course = course.clone();
+            for lesson in &mut course.lessons {
+                let (qualified, mut dependencies): (Vec<Ustr>, Vec<Ustr>) =
+                    lesson.dependencies.iter().copied().partition(is_qualified);
+                if qualified.is_empty() {
+                    continue;
+                }
+                let qualified: Vec<Ustr> = qualified
+                    .iter()
+                    .filter_map(|dependency| qualified_lesson_id(catalogs, dependency))
+                    .collect();
+                dependencies.extend(rewrite_dependencies(
+                    &qualified,
+                    &included,
+                    &all_dependencies,
+                ));
+                lesson.dependencies = dependencies;
+            }
+            course
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::Ustr;
+
+    use super::{parse_reference, resolve_references};
+    use crate::earmaster::{catalog::parse_catalog, EarMasterCourse};
+
+    /// Returns a course with the given ID and lessons, written as TOML.
+    fn course(id: &str, lessons: &str) -> EarMasterCourse {
+        parse_catalog(
+            &format!("{}.toml", id),
+            &format!(
+                "id = \"trane::test::{}\"\nname = \"{}\"\ndirectory_name = \"{}\"\nlessons = {}",
+                id, id, id, lessons
+            ),
+        )
+        .unwrap()
+    }
+
+    /// Verifies that qualified dependencies are split at the last separator.
+    #[test]
+    fn parses_references() {
+        assert_eq!(
+            parse_reference("rhythm_dictation::1.8"),
+            Some(("rhythm_dictation", "1.8"))
+        );
+        assert_eq!(
+            parse_reference("trane::music::earmaster::rhythm_dictation::1.8"),
+            Some(("trane::music::earmaster::rhythm_dictation", "1.8"))
+        );
+        assert_eq!(parse_reference("1.8"), None);
+    }
+
+    /// Verifies that qualified dependencies are resolved to full lesson IDs, and that dependencies
+    /// on lessons left out are replaced with the dependencies of those lessons.
+    #[test]
+    fn resolves_references() {
+        let catalogs = vec![
+            course(
+                "rhythm",
+                r#"[
+                    { id = "1.1", name = "A" },
+                    { id = "1.2", name = "B", dependencies = ["1.1"] },
+                ]"#,
+            ),
+            course(
+                "melody",
+                r#"[
+                    { id = "1.1", name = "C" },
+                    { id = "1.2", name = "D", dependencies = ["1.1", "rhythm::1.2"] },
+                    { id = "1.3", name = "E", dependencies = ["trane::test::rhythm::1.1"] },
+                ]"#,
+            ),
+        ];
+        let mut courses = catalogs.clone();
+        courses[0].retain_lessons(|lesson| lesson.id != "1.2");
+        let dependencies = |courses: &[EarMasterCourse]| -> Vec<Vec<Ustr>> {
+            courses[1]
+                .lessons
+                .iter()
+                .map(|lesson| lesson.dependencies.clone())
+                .collect()
+        };
+        let resolved = resolve_references(&catalogs, &courses);
+        assert_eq!(
+            dependencies(&resolved),
+            vec![
+                vec![],
+                vec![Ustr::from("1.1"), Ustr::from("trane::test::rhythm::1.1")],
+                vec![Ustr::from("trane::test::rhythm::1.1")],
+            ]
+        );
+
+        // Resolving the references again does not change them.
+        assert_eq!(
+            dependencies(&resolve_references(&catalogs, &resolved)),
+            dependencies(&resolved)
+        );
+    }
+}
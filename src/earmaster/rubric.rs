@@ -0,0 +1,196 @@
+//! Contains the scoring rubrics written to the back of every exercise. A rubric maps the result
+//! reported by EarMaster to Trane's 1-5 scale, so that the scores are consistent across lessons and
+//! activities.
+use serde::Deserialize;
+
+use super::locale::{fill, Strings};
+
+/// The kinds of activity in EarMaster, which determine how the results should be judged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    /// Activities in which the student identifies or compares what they hear.
+    #[default]
+    Identification,
+
+    /// Activities in which the student writes down what they hear.
+    Dictation,
+
+    /// Activities in which the student sings into the microphone.
+    Singing,
+
+    /// Activities in which the student claps or taps a rhythm.
+    Performance,
+}
+
+/// The default minimum percentages required for the scores 5, 4, 3, and 2.
+const DEFAULT_THRESHOLDS: [u32; 4] = [95, 85, 70, 50];
+
+/// The default number of attempts per question after which the score is lowered.
+const DEFAULT_MAX_ATTEMPTS: u32 = 1;
+
+/// Describes how to translate the results of an EarMaster exercise into a Trane score.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ScoringRubric {
+    /// The kind of activity, which selects the guidance shown along with the score table.
+    pub activity: ActivityKind,
+
+    /// The minimum percentages of correct answers required for the scores 5, 4, 3, and 2, in that
+    /// order. Lower results are scored as 1.
+    pub thresholds: Vec<u32>,
+
+    /// The number of attempts per question that can be used on average without lowering the
+    /// score.
+    pub max_attempts: u32,
+
+    /// Additional guidance appended to the rubric.
+    pub notes: Option<String>,
+}
+
+impl Default for ScoringRubric {
+    fn default() -> Self {
+        Self {
+            activity: ActivityKind::default(),
+            thresholds: DEFAULT_THRESHOLDS.to_vec(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            notes: None,
+        }
+    }
+}
+
+impl ScoringRubric {
+    /// Returns the problems with the rubric, if any.
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = vec![];
+        if self.thresholds.len() != DEFAULT_THRESHOLDS.len() {
+            issues.push(format!(
+                "the rubric must have {} thresholds, found {}",
+                DEFAULT_THRESHOLDS.len(),
+                self.thresholds.len()
+            ));
+        }
+        if self.thresholds.iter().any(|threshold| *threshold > 100) {
+            issues.push("the rubric thresholds must be between 0 and 100".to_string());
+        }
+        if self.thresholds.windows(2).any(|pair| pair[0] <= pair[1]) {
+            issues.push("the rubric thresholds must be in decreasing order".to_string());
+        }
+        if self.max_attempts == 0 {
+            issues.push("the rubric must allow at least one attempt".to_string());
+        }
+        issues
+    }
+
+    /// Returns the score from 1 to 5 for the given percentage of correct answers and, if known,
+    /// the average number of attempts per question.
+    pub fn score(&self, percentage: f32, attempts: Option<f32>) -> u8 {
+        let position = self
+            .thresholds
+            .iter()
+            .position(|threshold| percentage >= *threshold as f32)
+            .unwrap_or(self.thresholds.len());
+        let score = 5 - position.min(4) as u8;
+        match attempts {
+            Some(attempts) if attempts > self.max_attempts as f32 => (score - 1).max(1),
+            _ => score,
+        }
+    }
+
+    /// Returns the contents of the back of the exercise cards, written with the given templates
+    /// for the app with the given name.
+    pub fn back_contents(&self, strings: &Strings, app: &str) -> String {
+        let mut rows = vec![];
+        let mut upper_bound: Option<u32> = None;
+        for (index, threshold) in self.thresholds.iter().enumerate() {
+            let min = threshold.to_string();
+            let range = match upper_bound {
+                None => fill(&strings.rubric_min, &[("min", &min)]),
+                Some(bound) => fill(
+                    &strings.rubric_range,
+                    &[("min", &min), ("max", &(bound - 1).to_string())],
+                ),
+            };
+            rows.push(format!("| {} | {} |", range, 5 - index));
+            upper_bound = Some(*threshold);
+        }
+        if let Some(bound) = upper_bound {
+            let range = fill(&strings.rubric_max, &[("max", &bound.to_string())]);
+            rows.push(format!("| {} | 1 |", range));
+        }
+
+        let attempts = if self.max_attempts == 1 {
+            strings.rubric_one_attempt.clone()
+        } else {
+            fill(
+                &strings.rubric_many_attempts,
+                &[("count", &self.max_attempts.to_string())],
+            )
+        };
+        let app = [("app", app)];
+        let mut contents = format!(
+            "{}\n\n| {} | {} |\n| --- | --- |\n{}\n\n{}\n\n{}\n",
+            fill(&strings.rubric_intro, &app),
+            fill(&strings.rubric_result, &app),
+            strings.rubric_score,
+            rows.join("\n"),
+            fill(&strings.rubric_attempts, &[("attempts", &attempts)]),
+            fill(strings.guidance(self.activity), &app)
+        );
+        if let Some(notes) = &self.notes {
+            contents.push('\n');
+            contents.push_str(notes.trim_end());
+            contents.push('\n');
+        }
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{ActivityKind, ScoringRubric};
+    use crate::earmaster::locale::Strings;
+
+    #[test]
+    fn back_contents() {
+        let rubric = ScoringRubric {
+            activity: ActivityKind::Performance,
+            thresholds: vec![90, 80, 60, 40],
+            max_attempts: 2,
+            notes: Some("Use headphones.".to_string()),
+        };
+        assert!(rubric.issues().is_empty());
+        assert_eq!(
+            rubric.back_contents(&Strings::default(), "EarMaster"),
+            indoc! {"
+                Score this exercise using the result reported by EarMaster:
+
+                | EarMaster result | Score |
+                | --- | --- |
+                | 90% or more | 5 |
+                | 80% to 89% | 4 |
+                | 60% to 79% | 3 |
+                | 40% to 59% | 2 |
+                | Less than 40% | 1 |
+
+                Lower the score by one if you needed more than 2 attempts on most questions.
+
+                Lower the score by one if you had to reduce the tempo below the default to pass the exercise.
+
+                Use headphones.
+            "}
+        );
+
+        assert_eq!(rubric.score(95.0, None), 5);
+        assert_eq!(rubric.score(85.0, Some(3.0)), 3);
+        assert_eq!(rubric.score(20.0, Some(1.0)), 1);
+
+        let invalid = ScoringRubric {
+            thresholds: vec![90, 95, 70],
+            ..Default::default()
+        };
+        assert_eq!(invalid.issues().len(), 2);
+    }
+}
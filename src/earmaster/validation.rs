@@ -0,0 +1,558 @@
+//! Static validation of the dependency graph described by the EarMaster catalogs. Trane silently
+//! ignores dependencies on unknown lessons or courses, so the catalogs are checked before anything
+//! is written to disk.
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+};
+
+use anyhow::{bail, Result};
+use ustr::Ustr;
+
+use super::{
+    app::CatalogApp,
+    parallel::parallel_lessons,
+    reference::{find_course, full_dependency_id, is_qualified, parse_reference},
+    version, EarMasterCourse,
+};
+
+/// An issue found while validating the catalogs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The catalog file from which the offending course was loaded.
+    pub source: String,
+
+    /// The ID of the course with the issue.
+    pub course_id: Ustr,
+
+    /// The short ID of the offending lesson, if the issue is specific to a lesson.
+    pub lesson_id: Option<Ustr>,
+
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.lesson_id {
+            Some(lesson_id) => write!(
+                f,
+                "{}: course {}, lesson {}: {}",
+                self.source, self.course_id, lesson_id, self.message
+            ),
+            None => write!(
+                f,
+                "{}: course {}: {}",
+                self.source, self.course_id, self.message
+            ),
+        }
+    }
+}
+
+/// Returns the first cycle found in the graph with the given edges, listing the nodes in the order
+/// in which they are visited. The nodes are visited in the order of the keys of the map so that
+/// the same cycle is always reported.
+fn find_cycle(edges: &BTreeMap<Ustr, Vec<Ustr>>) -> Option<Vec<Ustr>> {
+    fn visit(
+        node: Ustr,
+        edges: &BTreeMap<Ustr, Vec<Ustr>>,
+        finished: &mut HashSet<Ustr>,
+        stack: &mut Vec<Ustr>,
+    ) -> Option<Vec<Ustr>> {
+        if finished.contains(&node) {
+            return None;
+        }
+        if let Some(start) = stack.iter().position(|n| *n == node) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(node);
+            return Some(cycle);
+        }
+
+        stack.push(node);
+        for dependency in edges.get(&node).into_iter().flatten() {
+            if let Some(cycle) = visit(*dependency, edges, finished, stack) {
+                return Some(cycle);
+            }
+        }
+        stack.pop();
+        finished.insert(node);
+        None
+    }
+
+    let mut finished = HashSet::new();
+    edges
+        .keys()
+        .find_map(|node| visit(*node, edges, &mut finished, &mut vec![]))
+}
+
+/// Formats a cycle as a chain of IDs.
+fn format_cycle(cycle: &[Ustr]) -> String {
+    cycle
+        .iter()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Returns all the issues found in a single course.
+fn course_issues(course: &EarMasterCourse, course_ids: &HashSet<Ustr>) -> Vec<ValidationIssue> {
+    let issue = |lesson_id: Option<Ustr>, message: String| ValidationIssue {
+        source: course.source.clone(),
+        course_id: course.id,
+        lesson_id,
+        message,
+    };
+    let mut issues = vec![];
+
+    // Check the dependencies of the course itself.
+    for dependency in &course.dependencies {
+        if *dependency == course.id {
+            issues.push(issue(None, "the course depends on itself".to_string()));
+        } else if !course_ids.contains(dependency) {
+            issues.push(issue(
+                None,
+                format!("unknown course dependency {}", dependency),
+            ));
+        }
+    }
+
+    // Check the scoring rubric.
+    for message in course.rubric.issues() {
+        issues.push(issue(None, message));
+    }
+
+    // Check the description of the app.
+    if let Some(CatalogApp::Custom(app)) = &course.app {
+        for message in app.issues(&course.strings) {
+            issues.push(issue(None, message));
+        }
+    }
+
+    // Check the musictheory.net presets, which only the lessons of that app have.
+    for lesson in &course.lessons {
+        match (&lesson.preset, course.is_musictheory()) {
+            (Some(preset), true) => {
+                for message in preset.issues() {
+                    issues.push(issue(Some(lesson.id), message));
+                }
+            }
+            (Some(_), false) => issues.push(issue(
+                Some(lesson.id),
+                "only musictheory.net lessons can have a preset".to_string(),
+            )),
+            (None, true) => issues.push(issue(
+                Some(lesson.id),
+                "the lesson has no musictheory.net preset".to_string(),
+            )),
+            (None, false) => {}
+        }
+    }
+
+    // Check for duplicate units and units without lessons.
+    let mut unit_ids = HashSet::new();
+    for unit in &course.units {
+        if !unit_ids.insert(unit.id.as_str()) {
+            issues.push(issue(None, format!("duplicate unit ID {}", unit.id)));
+        } else if !course
+            .lessons
+            .iter()
+            .any(|lesson| lesson.unit_id() == unit.id)
+        {
+            issues.push(issue(None, format!("unit {} has no lessons", unit.id)));
+        }
+    }
+
+    // Check the changes described for earlier releases of EarMaster.
+    for message in version::version_issues(course) {
+        issues.push(issue(None, message));
+    }
+
+    // Check for duplicate lesson IDs.
+    let mut lesson_counts: HashMap<Ustr, usize> = HashMap::new();
+    for lesson in &course.lessons {
+        *lesson_counts.entry(lesson.id).or_default() += 1;
+        if lesson_counts[&lesson.id] == 2 {
+            issues.push(issue(Some(lesson.id), "duplicate lesson ID".to_string()));
+        }
+    }
+
+    // Check that the name templates only use the content described in the descriptors.
+    for lesson in &course.lessons {
+        if let Some(template) = &lesson.name_template {
+            for placeholder in lesson.descriptor.missing_placeholders(template) {
+                issues.push(issue(
+                    Some(lesson.id),
+                    format!(
+                        "the name uses {} but the lesson does not describe its value",
+                        placeholder
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Check the dependencies of each lesson.
+    for lesson in &course.lessons {
+        // The dependencies on the lessons of other courses are checked with all the courses.
+        for dependency in lesson
+            .dependencies
+            .iter()
+            .filter(|dependency| !is_qualified(dependency))
+        {
+            if *dependency == lesson.id {
+                issues.push(issue(
+                    Some(lesson.id),
+                    "the lesson depends on itself".to_string(),
+                ));
+            } else if !lesson_counts.contains_key(dependency) {
+                issues.push(issue(
+                    Some(lesson.id),
+                    format!("unknown lesson dependency {}", dependency),
+                ));
+            }
+        }
+        for superseded in lesson.superseded_lessons() {
+            if superseded == lesson.id {
+                issues.push(issue(
+                    Some(lesson.id),
+                    "the lesson supersedes itself".to_string(),
+                ));
+            } else if !lesson_counts.contains_key(&superseded) {
+                issues.push(issue(
+                    Some(lesson.id),
+                    format!("unknown superseded lesson {}", superseded),
+                ));
+            }
+        }
+    }
+
+    // Check for cycles among the lessons. Self-dependencies were already reported above.
+    let mut edges: BTreeMap<Ustr, Vec<Ustr>> = BTreeMap::new();
+    for lesson in &course.lessons {
+        edges.entry(lesson.id).or_default().extend(
+            lesson
+                .dependencies
+                .iter()
+                .filter(|dependency| **dependency != lesson.id && !is_qualified(dependency)),
+        );
+    }
+    if let Some(cycle) = find_cycle(&edges) {
+        issues.push(issue(
+            Some(cycle[0]),
+            format!("dependency cycle {}", format_cycle(&cycle)),
+        ));
+    }
+    issues
+}
+
+/// Returns the issues with the dependencies of the lessons on the lessons of other courses.
+fn reference_issues(courses: &[EarMasterCourse]) -> Vec<ValidationIssue> {
+    let mut issues = vec![];
+    for course in courses {
+        for lesson in &course.lessons {
+            for dependency in lesson.dependencies.iter().filter(|d| is_qualified(d)) {
+                let (other, other_lesson) = parse_reference(dependency).unwrap();
+                let message = match find_course(courses, other) {
+                    None => format!("unknown course in lesson dependency {}", dependency),
+                    Some(other) if !other.lessons.iter().any(|l| l.id == other_lesson) => {
+                        format!("unknown lesson in lesson dependency {}", dependency)
+                    }
+                    Some(other) if other.id == course.id && lesson.id == other_lesson => {
+                        "the lesson depends on itself".to_string()
+                    }
+                    Some(_) => continue,
+                };
+                issues.push(ValidationIssue {
+                    source: course.source.clone(),
+                    course_id: course.id,
+                    lesson_id: Some(lesson.id),
+                    message,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Returns the issues with the parallel courses declared in the catalogs.
+fn parallel_issues(courses: &[EarMasterCourse]) -> Vec<ValidationIssue> {
+    let mut issues = vec![];
+    for course in courses {
+        let issue = |message: String| ValidationIssue {
+            source: course.source.clone(),
+            course_id: course.id,
+            lesson_id: None,
+            message,
+        };
+        let parallel = parallel_lessons(courses, course);
+        for declared in &course.parallel {
+            let Some(other) = find_course(courses, &declared.course) else {
+                issues.push(issue(format!(
+                    "unknown parallel course {}",
+                    declared.course
+                )));
+                continue;
+            };
+            if other.id == course.id {
+                issues.push(issue("the course is parallel to itself".to_string()));
+                continue;
+            }
+            for unit in &declared.units {
+                if !course.lessons.iter().any(|lesson| lesson.unit_id() == unit) {
+                    issues.push(issue(format!(
+                        "unknown unit {} in parallel course {}",
+                        unit, declared.course
+                    )));
+                }
+            }
+            if !parallel
+                .iter()
+                .any(|lesson| lesson.other_course.id == other.id)
+            {
+                issues.push(issue(format!(
+                    "no lessons are parallel to the lessons of {}",
+                    declared.course
+                )));
+            }
+        }
+    }
+    issues
+}
+
+/// Returns the edges of the graph of the dependencies of the lessons on the lessons of other
+/// courses, in which a lesson also depends on the courses on which its course depends, and those
+/// courses depend on all their lessons. Parallel lessons are included, since they can be turned
+/// into dependencies.
+fn cross_course_edges(courses: &[EarMasterCourse]) -> BTreeMap<Ustr, Vec<Ustr>> {
+    let mut edges: BTreeMap<Ustr, Vec<Ustr>> = BTreeMap::new();
+    for course in courses {
+        edges.entry(course.id).or_default().extend(
+            course
+                .lessons
+                .iter()
+                .map(|lesson| lesson.full_id(&course.id)),
+        );
+        let parallel = parallel_lessons(courses, course);
+        for lesson in &course.lessons {
+            edges.entry(lesson.full_id(&course.id)).or_default().extend(
+                lesson
+                    .dependencies
+                    .iter()
+                    .filter(|dependency| is_qualified(dependency))
+                    .filter_map(|dependency| full_dependency_id(courses, course, dependency))
+                    .chain(
+                        parallel
+                            .iter()
+                            .filter(|other| other.lesson.id == lesson.id)
+                            .map(|other| other.other_lesson.full_id(&other.other_course.id)),
+                    )
+                    .chain(
+                        course
+                            .dependencies
+                            .iter()
+                            .filter(|dependency| **dependency != course.id)
+                            .copied(),
+                    ),
+            );
+        }
+    }
+    edges
+}
+
+/// Returns all the issues found in the given courses.
+pub fn find_issues(courses: &[EarMasterCourse]) -> Vec<ValidationIssue> {
+    let mut issues = vec![];
+
+    // Check for duplicate course IDs.
+    let mut course_ids = HashSet::new();
+    for course in courses {
+        if !course_ids.insert(course.id) {
+            issues.push(ValidationIssue {
+                source: course.source.clone(),
+                course_id: course.id,
+                lesson_id: None,
+                message: "duplicate course ID".to_string(),
+            });
+        }
+    }
+
+    for course in courses {
+        issues.extend(course_issues(course, &course_ids));
+    }
+    issues.extend(reference_issues(courses));
+    issues.extend(parallel_issues(courses));
+
+    // Check for cycles among the courses.
+    let mut edges: BTreeMap<Ustr, Vec<Ustr>> = BTreeMap::new();
+    for course in courses {
+        edges.entry(course.id).or_default().extend(
+            course
+                .dependencies
+                .iter()
+                .filter(|dependency| **dependency != course.id),
+        );
+    }
+    if let Some(cycle) = find_cycle(&edges) {
+        let course = courses.iter().find(|course| course.id == cycle[0]).unwrap();
+        issues.push(ValidationIssue {
+            source: course.source.clone(),
+            course_id: course.id,
+            lesson_id: None,
+            message: format!("course dependency cycle {}", format_cycle(&cycle)),
+        });
+    } else if let Some(cycle) = find_cycle(&cross_course_edges(courses)) {
+        // Without any cycles among the courses, the cycle goes through a lesson dependency. The
+        // issue is reported for a lesson in the cycle, which might start with a course.
+        let (course, lesson) = courses
+            .iter()
+            .flat_map(|course| course.lessons.iter().map(move |lesson| (course, lesson)))
+            .find(|(course, lesson)| cycle.contains(&lesson.full_id(&course.id)))
+            .unwrap();
+        issues.push(ValidationIssue {
+            source: course.source.clone(),
+            course_id: course.id,
+            lesson_id: Some(lesson.id),
+            message: format!(
+                "lesson dependency cycle across courses {}",
+                format_cycle(&cycle)
+            ),
+        });
+    }
+    issues
+}
+
+/// Validates the given courses and returns an error listing every issue found.
+pub fn validate_courses(courses: &[EarMasterCourse]) -> Result<()> {
+    let issues = find_issues(courses);
+    if !issues.is_empty() {
+        let lines: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        bail!(
+            "found {} issue(s) in the catalogs:\n{}",
+            issues.len(),
+            lines.join("\n")
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::Ustr;
+
+    use super::find_issues;
+    use crate::earmaster::catalog::parse_catalog;
+
+    /// Verifies the issues within a course, such as unknown, duplicate, or cyclic lesson
+    /// dependencies, empty units, and names using scale degrees the lesson does not describe.
+    #[test]
+    fn reports_invalid_dependencies() -> anyhow::Result<()> {
+        let course = parse_catalog(
+            "test.toml",
+            r#"
+                id = "test"
+                name = "Test"
+                directory_name = "test"
+                dependencies = ["missing"]
+                units = [{ id = "2", title = "Empty" }]
+                lessons = [
+                    { id = "1.1", name = "A", dependencies = ["1.3"] },
+                    { id = "1.2", name = "B", dependencies = ["1.2", "1.9"], superseded = ["1.8"] },
+                    { id = "1.3", name = "C", dependencies = ["1.1"] },
+                    { id = "1.3", name = "D" },
+                    { id = "1.4", name = "{start} - Re" },
+                ]
+            "#,
+        )?;
+        let messages: Vec<(Option<Ustr>, String)> = find_issues(&[course])
+            .into_iter()
+            .map(|issue| (issue.lesson_id, issue.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (None, "unknown course dependency missing".to_string()),
+                (None, "unit 2 has no lessons".to_string()),
+                (Some("1.3".into()), "duplicate lesson ID".to_string()),
+                (
+                    Some("1.4".into()),
+                    "the name uses {start} but the lesson does not describe its value".to_string()
+                ),
+                (
+                    Some("1.2".into()),
+                    "the lesson depends on itself".to_string()
+                ),
+                (
+                    Some("1.2".into()),
+                    "unknown lesson dependency 1.9".to_string()
+                ),
+                (
+                    Some("1.2".into()),
+                    "unknown superseded lesson 1.8".to_string()
+                ),
+                (
+                    Some("1.1".into()),
+                    "dependency cycle 1.1 -> 1.3 -> 1.1".to_string()
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Verifies the issues with the dependencies on the lessons of other courses, including the
+    /// cycles formed with the dependencies between the courses.
+    #[test]
+    fn reports_invalid_lesson_references() -> anyhow::Result<()> {
+        let rhythm = parse_catalog(
+            "rhythm.toml",
+            r#"
+                id = "trane::test::rhythm"
+                name = "Rhythm"
+                directory_name = "rhythm"
+                dependencies = ["trane::test::melody"]
+                lessons = [{ id = "1.1", name = "A" }]
+            "#,
+        )?;
+        let melody = parse_catalog(
+            "melody.toml",
+            r#"
+                id = "trane::test::melody"
+                name = "Melody"
+                directory_name = "melody"
+                lessons = [
+                    { id = "1.1", name = "B", dependencies = ["rhythm::1.1"] },
+                    { id = "1.2", name = "C", dependencies = ["rhythm::1.9", "missing::1.1"] },
+                    { id = "1.3", name = "D", dependencies = ["melody::1.3"] },
+                ]
+            "#,
+        )?;
+        let messages: Vec<(Option<Ustr>, String)> = find_issues(&[rhythm, melody])
+            .into_iter()
+            .map(|issue| (issue.lesson_id, issue.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    Some("1.2".into()),
+                    "unknown lesson in lesson dependency rhythm::1.9".to_string()
+                ),
+                (
+                    Some("1.2".into()),
+                    "unknown course in lesson dependency missing::1.1".to_string()
+                ),
+                (
+                    Some("1.3".into()),
+                    "the lesson depends on itself".to_string()
+                ),
+                (
+                    Some("1.1".into()),
+                    "lesson dependency cycle across courses trane::test::melody -> \
+                    trane::test::melody::1.1 -> trane::test::rhythm::1.1 -> trane::test::melody"
+                        .to_string()
+                ),
+            ]
+        );
+        Ok(())
+    }
+}
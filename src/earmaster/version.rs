@@ -0,0 +1,238 @@
+//! Contains the supported releases of EarMaster. The lessons in the catalogs follow the latest
+//! release, and each catalog can describe how the lessons differ in earlier releases. The Trane
+//! IDs of the lessons never change, so that the practice history of a lesson that was renumbered
+//! between releases is kept.
+use std::{collections::BTreeMap, fmt};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+use ustr::Ustr;
+
+use super::EarMasterCourse;
+
+/// The releases of EarMaster supported by the catalogs. Earlier releases are only added once the
+/// catalogs describe how their lessons differ from the latest release.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, ValueEnum,
+)]
+pub enum EarMasterVersion {
+    /// EarMaster 7.2, which the lessons in the catalogs follow.
+    #[default]
+    #[serde(rename = "7.2")]
+    #[value(name = "7.2")]
+    V7_2,
+}
+
+impl fmt::Display for EarMasterVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EarMasterVersion::V7_2 => write!(f, "7.2"),
+        }
+    }
+}
+
+/// Describes how the lessons of a course in an earlier release differ from the ones in the
+/// catalog. Lessons are referenced by their IDs in the catalog.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VersionChanges {
+    /// The lessons with a different number, mapped to their number in the release.
+    pub renumbered: BTreeMap<Ustr, String>,
+
+    /// The lessons with a different name, mapped to their name in the release.
+    pub renamed: BTreeMap<Ustr, String>,
+
+    /// The lessons that are not part of the release.
+    pub missing: Vec<Ustr>,
+}
+
+/// Returns the course with the given changes applied. Missing lessons are removed, and renumbered
+/// or renamed lessons keep their IDs but show the number and name used in the release.
+fn apply_changes(course: &EarMasterCourse, changes: &VersionChanges) -> EarMasterCourse {
+    let mut versioned = course.clone();
+    versioned.retain_lessons(|lesson| !changes.missing.contains(&lesson.id));
+    for lesson in &mut versioned.lessons {
+        if let Some(number) = changes.renumbered.get(&lesson.id) {
+            lesson.number = Some(number.clone());
+        }
+        if let Some(name) = changes.renamed.get(&lesson.id) {
+            lesson.rename(name, versioned.notation, &versioned.strings);
+        }
+    }
+    versioned
+}
+
+/// Returns the course with the lessons of the given release of EarMaster.
+pub fn course_for_version(course: &EarMasterCourse, version: EarMasterVersion) -> EarMasterCourse {
+    let mut versioned = match course.versions.get(&version) {
+        Some(changes) => apply_changes(course, changes),
+        None => course.clone(),
+    };
+    versioned.version = version;
+    versioned
+}
+
+/// Returns the problems with the changes described for the earlier releases of the course.
+pub fn version_issues(course: &EarMasterCourse) -> Vec<String> {
+    let mut issues = vec![];
+    let is_known = |id: &Ustr| course.lessons.iter().any(|lesson| lesson.id == *id);
+    for (version, changes) in &course.versions {
+        if *version == EarMasterVersion::default() {
+            issues.push(format!(
+                "the lessons in EarMaster {} cannot differ from the catalog",
+                version
+            ));
+        }
+        let ids = changes
+            .renumbered
+            .keys()
+            .chain(changes.renamed.keys())
+            .chain(changes.missing.iter());
+        for id in ids {
+            if !is_known(id) {
+                issues.push(format!(
+                    "unknown lesson {} in the changes for EarMaster {}",
+                    id, version
+                ));
+            }
+        }
+
+        // Verify that no two lessons share the same number in the release.
+        let versioned = apply_changes(course, changes);
+        let mut numbers: BTreeMap<&str, usize> = BTreeMap::new();
+        for lesson in &versioned.lessons {
+            *numbers.entry(lesson.number()).or_default() += 1;
+        }
+        for (number, count) in numbers {
+            if count > 1 {
+                issues.push(format!(
+                    "lesson number {} is used by {} lessons in EarMaster {}",
+                    number, count, version
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Returns the given courses with the lessons of the given release of EarMaster.
+pub fn courses_for_version(
+    courses: &[EarMasterCourse],
+    version: EarMasterVersion,
+) -> Vec<EarMasterCourse> {
+    courses
+        .iter()
+        .map(|course| course_for_version(course, version))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::ValueEnum;
+    use ustr::Ustr;
+
+    use super::{apply_changes, version_issues, EarMasterVersion, VersionChanges};
+    use crate::{
+        earmaster::catalog::{bundled_catalogs, parse_catalog},
+        import::{match_results, ResultRow},
+    };
+
+    /// Verifies that renumbered lessons keep their IDs and that the dependencies on missing
+    /// lessons are rewritten.
+    #[test]
+    fn selects_version() -> anyhow::Result<()> {
+        let course = parse_catalog(
+            "test.toml",
+            r#"
+                id = "test"
+                name = "Test"
+                directory_name = "test"
+                lessons = [
+                    { id = "1.1", name = "A" },
+                    { id = "1.2", name = "B", dependencies = ["1.1"] },
+                    { id = "1.3", name = "C", dependencies = ["1.2"] },
+                ]
+            "#,
+        )?;
+        let changes: VersionChanges = toml::from_str(
+            r#"
+                renumbered = { "1.3" = "1.2" }
+                renamed = { "1.3" = "Old C" }
+                missing = ["1.2"]
+            "#,
+        )?;
+
+        let old = apply_changes(&course, &changes);
+        assert_eq!(old.lessons.len(), 2);
+        let lesson = &old.lessons[1];
+        assert_eq!(lesson.id, "1.3");
+        assert_eq!(lesson.number(), "1.2");
+        assert_eq!(lesson.name, "Old C");
+        assert_eq!(lesson.dependencies, vec![Ustr::from("1.1")]);
+        assert_eq!(course.lessons[2].number(), "1.3");
+        Ok(())
+    }
+
+    /// Verifies that a renumbered lesson keeps the Trane IDs of the lesson and its exercise, so
+    /// that the results exported with the number of an earlier release are added to the same
+    /// practice history.
+    #[test]
+    fn renumbered_lessons_keep_ids() -> anyhow::Result<()> {
+        let course = parse_catalog(
+            "test.toml",
+            r#"
+                id = "test"
+                name = "Test"
+                directory_name = "test"
+                lessons = [
+                    { id = "1.1", name = "A" },
+                    { id = "1.2", name = "B", dependencies = ["1.1"] },
+                ]
+            "#,
+        )?;
+        let changes: VersionChanges = toml::from_str(r#"renumbered = { "1.2" = "2.1" }"#)?;
+        let old = apply_changes(&course, &changes);
+        assert_eq!(old.lessons[1].number(), "2.1");
+        assert_eq!(
+            old.lessons[1].full_id(&old.id),
+            course.lessons[1].full_id(&course.id)
+        );
+
+        let row = |lesson: &str| ResultRow {
+            line: 2,
+            activity: "Test".to_string(),
+            lesson: lesson.to_string(),
+            percentage: 90.0,
+            attempts: None,
+            timestamp: 0,
+        };
+        let (matched, unmatched) = match_results(&[old], vec![row("2.1"), row("1.2")]);
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].exercise_id, Ustr::from("test::1.2::exercise"));
+        assert_eq!(unmatched.len(), 1);
+        Ok(())
+    }
+
+    /// Verifies that the bundled catalogs describe every supported release other than the latest,
+    /// which would otherwise be built with the lessons of the latest release, and that the changes
+    /// they describe are valid.
+    #[test]
+    fn bundled_catalogs_describe_releases() -> anyhow::Result<()> {
+        let courses = bundled_catalogs()?;
+        for version in EarMasterVersion::value_variants() {
+            if *version != EarMasterVersion::default() {
+                assert!(
+                    courses
+                        .iter()
+                        .any(|course| course.versions.contains_key(version)),
+                    "no catalog describes the lessons of EarMaster {}",
+                    version
+                );
+            }
+        }
+        for course in &courses {
+            assert_eq!(version_issues(course), Vec::<String>::new());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+//! Imports the statistics exported from EarMaster into the practice history of a Trane library, so
+//! that students who already practiced in EarMaster do not start every exercise from zero. The
+//! export is a CSV file with one row per result. The columns are identified by their headers, so
+//! their order does not matter:
+//!
+//! - `Activity`: the name of the EarMaster activity, such as "Interval Identification".
+//! - `Lesson`: the number of the lesson in EarMaster, such as "1.5".
+//! - `Score`: the percentage of correct answers, with or without the percent sign.
+//! - `Date`: the date of the result, as `YYYY-MM-DD` optionally followed by the time.
+//! - `Attempts`: optionally, the average number of attempts per question.
+use std::{collections::hash_map::Entry, fmt, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{NaiveDate, NaiveDateTime};
+use trane::{
+    course_library::CourseLibrary, data::MasteryScore, practice_stats::PracticeStats,
+    scheduler::ExerciseScheduler,
+};
+use ustr::{Ustr, UstrMap};
+
+use crate::earmaster::EarMasterCourse;
+
+/// A single result read from the EarMaster export.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResultRow {
+    /// The line of the file in which the result was found.
+    pub line: usize,
+
+    /// The name of the EarMaster activity.
+    pub activity: String,
+
+    /// The number of the lesson in EarMaster.
+    pub lesson: String,
+
+    /// The percentage of correct answers.
+    pub percentage: f32,
+
+    /// The average number of attempts per question, if the export includes it.
+    pub attempts: Option<f32>,
+
+    /// The time of the result, as a Unix timestamp.
+    pub timestamp: i64,
+}
+
+/// A result matched to the exercise generated for its lesson.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportedScore {
+    /// The result from which the score was computed.
+    pub row: ResultRow,
+
+    /// The ID of the exercise generated for the lesson.
+    pub exercise_id: Ustr,
+
+    /// The score from 1 to 5 computed with the rubric of the course.
+    pub score: u8,
+}
+
+impl fmt::Display for ImportedScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} {} ({}%) -> {} with score {}",
+            self.row.line,
+            self.row.activity,
+            self.row.lesson,
+            self.row.percentage,
+            self.exercise_id,
+            self.score
+        )
+    }
+}
+
+/// Splits a line of the CSV file into its fields. Fields can be quoted, in which case they can
+/// contain the delimiter and escaped quotes.
+fn split_fields(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+        .into_iter()
+        .map(|field| field.trim().to_string())
+        .collect()
+}
+
+/// Parses the date of a result into a Unix timestamp. Dates without a time are placed at noon so
+/// that they do not change day in any time zone.
+fn parse_date(date: &str) -> Option<i64> {
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(date, format) {
+            return Some(date_time.and_utc().timestamp());
+        }
+    }
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(12, 0, 0))
+        .map(|date_time| date_time.and_utc().timestamp())
+}
+
+/// Parses the contents of an EarMaster export. Both commas and semicolons are accepted as
+/// delimiters.
+pub fn parse_results(contents: &str) -> Result<Vec<ResultRow>> {
+    let mut lines = contents
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        bail!("the export is empty");
+    };
+    let delimiter = if header.contains(';') && !header.contains(',') {
+        ';'
+    } else {
+        ','
+    };
+    let headers: Vec<String> = split_fields(header, delimiter)
+        .into_iter()
+        .map(|header| header.to_lowercase())
+        .collect();
+    let column = |name: &str| headers.iter().position(|header| header == name);
+    let required = |name: &str| {
+        column(name).ok_or_else(|| anyhow!("the export does not have a {} column", name))
+    };
+    let activity_column = required("activity")?;
+    let lesson_column = required("lesson")?;
+    let score_column = required("score")?;
+    let date_column = required("date")?;
+    let attempts_column = column("attempts");
+
+    let mut rows = vec![];
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let fields = split_fields(line, delimiter);
+        let field = |column: usize| fields.get(column).map(String::as_str).unwrap_or_default();
+        let percentage: f32 = field(score_column)
+            .trim_end_matches('%')
+            .trim()
+            .parse()
+            .with_context(|| format!("line {}: invalid score", line_number))?;
+        let attempts = match attempts_column.map(field) {
+            None | Some("") => None,
+            Some(attempts) => Some(
+                attempts
+                    .parse()
+                    .with_context(|| format!("line {}: invalid attempts", line_number))?,
+            ),
+        };
+        let timestamp = parse_date(field(date_column))
+            .ok_or_else(|| anyhow!("line {}: invalid date", line_number))?;
+        rows.push(ResultRow {
+            line: line_number,
+            activity: field(activity_column).to_string(),
+            lesson: field(lesson_column).to_string(),
+            percentage,
+            attempts,
+            timestamp,
+        });
+    }
+    Ok(rows)
+}
+
+/// Matches each result to the exercise generated for its lesson and computes its score. The
+/// activity is matched against the name or directory name of the EarMaster courses, ignoring case,
+/// and the lesson is matched against the lesson numbers in the release of EarMaster of the courses.
+/// Courses for other apps are ignored, since their results are not in the export. Returns the
+/// matched scores and the results that could not be matched along with the reason.
+pub fn match_results(
+    courses: &[EarMasterCourse],
+    rows: Vec<ResultRow>,
+) -> (Vec<ImportedScore>, Vec<(ResultRow, String)>) {
+    let mut matched = vec![];
+    let mut unmatched = vec![];
+    for row in rows {
+        let Some(course) = courses.iter().find(|course| {
+            course.app.is_none()
+                && (course.name.eq_ignore_ascii_case(&row.activity)
+                    || course.directory_name.eq_ignore_ascii_case(&row.activity))
+        }) else {
+            let reason = format!("unknown activity {}", row.activity);
+            unmatched.push((row, reason));
+            continue;
+        };
+        let Some(lesson) = course
+            .lessons
+            .iter()
+            .find(|lesson| lesson.number() == row.lesson)
+        else {
+            let reason = format!("unknown lesson {} in {}", row.lesson, course.name);
+            unmatched.push((row, reason));
+            continue;
+        };
+        matched.push(ImportedScore {
+            exercise_id: lesson.exercise_id(&course.id),
+            score: course.rubric.score(row.percentage, row.attempts),
+            row,
+        });
+    }
+    (matched, unmatched)
+}
+
+/// Converts a score from 1 to 5 into a mastery score.
+fn mastery_score(score: u8) -> MasteryScore {
+    match score {
+        1 => MasteryScore::One,
+        2 => MasteryScore::Two,
+        3 => MasteryScore::Three,
+        4 => MasteryScore::Four,
+        _ => MasteryScore::Five,
+    }
+}
+
+/// The maximum number of trials read from the practice history of an exercise to find the results
+/// that were already imported.
+const MAX_HISTORY_LENGTH: usize = 100_000;
+
+/// Imports the results in the given export into the practice history of the Trane library. Results
+/// whose exercise is not in the library are reported as unmatched, and results which are already
+/// in the practice history of their exercise at the same time are skipped, so that importing the
+/// same export twice does not count its results twice. If `dry_run` is true, the library is only
+/// read to check the results, and the mapping is printed without scoring any exercise. Returns the
+/// number of scores that were, or would be, imported.
+pub fn import_results(
+    courses: &[EarMasterCourse],
+    export: &Path,
+    library_root: &Path,
+    dry_run: bool,
+) -> Result<usize> {
+    let contents = std::fs::read_to_string(export)
+        .with_context(|| format!("failed to read export {}", export.display()))?;
+    let rows = parse_results(&contents)
+        .with_context(|| format!("failed to parse export {}", export.display()))?;
+    let (matched, mut unmatched) = match_results(courses, rows);
+
+    let trane = trane::Trane::new(library_root, library_root)?;
+    let mut history: UstrMap<Vec<i64>> = UstrMap::default();
+    let mut imported = 0;
+    let mut skipped = 0;
+    for score in matched {
+        if trane.get_exercise_manifest(score.exercise_id).is_none() {
+            let reason = format!("exercise {} is not in the library", score.exercise_id);
+            unmatched.push((score.row, reason));
+            continue;
+        }
+        let timestamps = match history.entry(score.exercise_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(
+                trane
+                    .get_scores(score.exercise_id, MAX_HISTORY_LENGTH)?
+                    .into_iter()
+                    .map(|trial| trial.timestamp)
+                    .collect(),
+            ),
+        };
+        if timestamps.contains(&score.row.timestamp) {
+            println!("line {}: already imported", score.row.line);
+            skipped += 1;
+            continue;
+        }
+        timestamps.push(score.row.timestamp);
+        if !dry_run {
+            trane.score_exercise(
+                score.exercise_id,
+                mastery_score(score.score),
+                score.row.timestamp,
+            )?;
+        }
+        println!("{}", score);
+        imported += 1;
+    }
+
+    unmatched.sort_by_key(|(row, _)| row.line);
+    for (row, reason) in &unmatched {
+        println!("line {}: unmatched: {}", row.line, reason);
+    }
+    println!(
+        "{}{} imported, {} already imported, {} unmatched",
+        if dry_run { "Dry run: " } else { "" },
+        imported,
+        skipped,
+        unmatched.len()
+    );
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use trane::{data::MasteryScore, practice_stats::PracticeStats};
+
+    use super::import_results;
+    use crate::{
+        build_courses,
+        earmaster::{catalog, EarMasterCourse},
+        BuildOptions,
+    };
+
+    /// Verifies that the results are matched to the generated exercises and written to the
+    /// practice history, and that unmatched results are skipped.
+    #[test]
+    fn imports_results() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = temp_dir.path().join("library");
+        let mut courses = catalog::bundled_catalogs()?;
+        courses.retain(|course| course.directory_name == "scale_identification");
+        build_courses(&courses, &BuildOptions::default(), &library_root)?;
+
+        let export = temp_dir.path().join("results.csv");
+        fs::write(
+            &export,
+            "Activity;Lesson;Score;Date\n\
+            Scale Identification;1.1;96%;2023-01-02\n\
+            Scale Identification;9.9;50%;2023-01-02\n\
+            \"Chord Identification\";1.1;80%;2023-01-03 10:00\n",
+        )?;
+        assert_eq!(import_results(&courses, &export, &library_root, true)?, 1);
+        assert_eq!(import_results(&courses, &export, &library_root, false)?, 1);
+
+        let trane = trane::Trane::new(&library_root, &library_root)?;
+        let scores = trane.get_scores(
+            "trane::music::earmaster::scale_identification::1.1::exercise".into(),
+            10,
+        )?;
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].score, MasteryScore::Five.float_score());
+        Ok(())
+    }
+
+    /// Verifies that importing the same export twice does not add its results to the practice
+    /// history again, and that the results repeated in the export are only imported once.
+    #[test]
+    fn imports_results_once() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = temp_dir.path().join("library");
+        let courses = catalog::bundled_catalogs()?;
+        let scale_identification: Vec<EarMasterCourse> = courses
+            .iter()
+            .filter(|course| course.directory_name == "scale_identification")
+            .cloned()
+            .collect();
+        build_courses(
+            &scale_identification,
+            &BuildOptions::default(),
+            &library_root,
+        )?;
+
+        let export = temp_dir.path().join("results.csv");
+        fs::write(
+            &export,
+            "Activity,Lesson,Score,Date\n\
+            Scale Identification,1.1,96%,2023-01-02\n\
+            Scale Identification,1.1,96%,2023-01-02\n\
+            Scale Identification,1.1,60%,2023-01-03\n\
+            Chord Identification,1.1,80%,2023-01-03\n",
+        )?;
+        assert_eq!(import_results(&courses, &export, &library_root, true)?, 2);
+        assert_eq!(import_results(&courses, &export, &library_root, false)?, 2);
+        assert_eq!(import_results(&courses, &export, &library_root, false)?, 0);
+        assert_eq!(import_results(&courses, &export, &library_root, true)?, 0);
+
+        let trane = trane::Trane::new(&library_root, &library_root)?;
+        let scores = trane.get_scores(
+            "trane::music::earmaster::scale_identification::1.1::exercise".into(),
+            10,
+        )?;
+        assert_eq!(scores.len(), 2);
+        Ok(())
+    }
+}
@@ -1,56 +1,179 @@
 //! Code to generate all the music courses.
+mod cli;
 mod earmaster;
+mod import;
+mod output;
 
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use clap::Parser;
+
+use cli::Cli;
+use earmaster::{
+    edition::{self, Edition},
+    locale::{self, Locale},
+    notation::{self, Notation},
+    parallel, reference, validation,
+    version::{self, EarMasterVersion},
+    EarMasterCourse,
+};
+use output::{BuildPlan, Change};
 
 static AUTHORS: &str = "The Trane Project";
 
-fn build_courses(library_root: &Path) -> Result<()> {
-    let course_builders = vec![
-        earmaster::chord_identification::course_builder(),
-        earmaster::chord_inversions::course_builder(),
-        earmaster::chord_progressions::course_builder(),
-        earmaster::interval_comparison::course_builder(),
-        earmaster::interval_identification::course_builder(),
-        earmaster::interval_singing::course_builder(),
-        earmaster::melody_dictation::course_builder(),
-        earmaster::melody_sight_singing::course_builder(),
-        earmaster::melody_singback::course_builder(),
-        earmaster::rhythm_clapback::course_builder(),
-        earmaster::rhythm_dictation::course_builder(),
-        earmaster::rhythm_error_detection::course_builder(),
-        earmaster::rhythm_sight_reading::course_builder(),
-        earmaster::scale_identification::course_builder(),
-    ];
-
-    for course_builder in course_builders {
-        course_builder.build(library_root)?;
-        println!("Built {} course", course_builder.course_manifest.name);
+/// Returns the courses matching the given IDs or directory names. All courses are returned if no
+/// filter is given.
+fn select_courses<'a>(
+    courses: &'a [EarMasterCourse],
+    only: &[String],
+) -> Result<Vec<&'a EarMasterCourse>> {
+    let matches = |course: &EarMasterCourse, name: &String| {
+        course.id == name.as_str() || course.directory_name == *name
+    };
+    for name in only {
+        if !courses.iter().any(|course| matches(course, name)) {
+            bail!("unknown course {}", name);
+        }
+    }
+    Ok(courses
+        .iter()
+        .filter(|course| only.is_empty() || only.iter().any(|name| matches(course, name)))
+        .collect())
+}
+
+/// Options that control how the courses are built.
+#[derive(Clone, Debug, Default)]
+pub struct BuildOptions {
+    /// If not empty, only the courses with the given IDs or directory names are built.
+    pub only: Vec<String>,
+
+    /// If true, the changes are computed and printed but not written to disk.
+    pub dry_run: bool,
+
+    /// If not empty, only the content included in at least one of these editions is built.
+    pub editions: Vec<Edition>,
+
+    /// The release of EarMaster whose lessons are built.
+    pub version: EarMasterVersion,
+
+    /// The locale into which the courses are translated, or `None` to build them in English.
+    pub locale: Option<Locale>,
+
+    /// The notation in which the scale degrees in the lesson names are written.
+    pub notation: Notation,
+
+    /// If true, every lesson depends on its parallel lessons in other courses, which replace the
+    /// dependencies on those courses where possible.
+    pub parallel_dependencies: bool,
+}
+
+/// Builds the given courses under the library root. All the courses are validated first, so that
+/// dependencies on the courses left out are also checked, and nothing is written if any issue is
+/// found. If enabled, the parallel lessons are then linked with dependencies. The courses are then
+/// adapted to the selected release of EarMaster, restricted to the selected editions, if any,
+/// translated into the selected locale, and their lesson names are written in the selected
+/// notation. The dependencies on the lessons of other courses are resolved last, once the lessons
+/// left in every course are known. Only the files that changed are written, and files from a
+/// previous build that are no longer generated are removed.
+fn build_courses(
+    catalogs: &[EarMasterCourse],
+    options: &BuildOptions,
+    library_root: &Path,
+) -> Result<Vec<Change>> {
+    validation::validate_courses(catalogs)?;
+    let catalogs = if options.parallel_dependencies {
+        parallel::link_parallel_lessons(catalogs)
+    } else {
+        catalogs.to_vec()
+    };
+    let courses = version::courses_for_version(&catalogs, options.version);
+    let courses = edition::restrict_courses(&courses, &options.editions)?;
+    let courses = locale::localize_courses(&catalogs, &courses, options.locale.as_ref())?;
+    let courses = notation::courses_with_notation(&courses, options.notation)?;
+    let courses = reference::resolve_references(&catalogs, &courses);
+    let course_builders: Vec<_> = select_courses(&courses, &options.only)?
+        .into_iter()
+        .map(|course| course.course_builder())
+        .collect();
+    let plan = BuildPlan::new(&course_builders)?;
+
+    // Generated courses that are no longer in the catalogs are only removed when building all of
+    // them, since otherwise they might just be the courses that were left out.
+    let changes = output::diff(&plan, library_root, options.only.is_empty())?;
+    for change in &changes {
+        println!("{}", change);
     }
-    Ok(())
+    if !options.dry_run {
+        output::apply(&plan, &changes, library_root)?;
+        for course_builder in &course_builders {
+            println!("Built {} course", course_builder.course_manifest.name);
+        }
+    }
+
+    let count = |f: fn(&Change) -> bool| changes.iter().filter(|change| f(change)).count();
+    println!(
+        "{}{} added, {} changed, {} removed",
+        if options.dry_run { "Dry run: " } else { "" },
+        count(|change| matches!(change, Change::Added(_))),
+        count(|change| matches!(change, Change::Changed(_))),
+        count(|change| matches!(change, Change::Removed(_))),
+    );
+    Ok(changes)
 }
 
 fn main() -> Result<()> {
-    let curr_dir = std::env::current_dir()?;
-    build_courses(curr_dir.as_path())
+    Cli::parse().run()
 }
 
 #[cfg(test)]
 mod tests {
     use trane::scheduler::ExerciseScheduler;
 
-    use crate::build_courses;
+    use crate::{
+        build_courses,
+        earmaster::{catalog, edition, locale},
+        BuildOptions,
+    };
 
     #[test]
     fn open_library() -> anyhow::Result<()> {
         let temp_dir = tempfile::TempDir::new()?;
         let library_root = &temp_dir.path().to_path_buf();
-        build_courses(library_root)?;
+        build_courses(
+            &catalog::bundled_catalogs()?,
+            &BuildOptions::default(),
+            library_root,
+        )?;
         let trane = trane::Trane::new(library_root, library_root)?;
         let batch = trane.get_exercise_batch(None)?;
         assert!(!batch.is_empty());
         Ok(())
     }
+
+    /// Verifies that the courses can be built for a restricted edition in a non-default locale,
+    /// whose translations of the content left out by the edition are not reported.
+    #[test]
+    fn build_edition_in_locale() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let options = BuildOptions {
+            editions: edition::select_editions(
+                &edition::bundled_editions()?,
+                &["essential".to_string()],
+            )?,
+            locale: Some(locale::parse_locale(
+                "de.toml",
+                r#"
+                    id = "de"
+                    name = "Deutsch"
+                    courses.harmonic_dictation.units = { "1" = "Basslinien mit I und V" }
+                "#,
+            )?),
+            ..Default::default()
+        };
+        let changes = build_courses(&catalog::bundled_catalogs()?, &options, temp_dir.path())?;
+        assert!(!changes.is_empty());
+        assert!(!temp_dir.path().join("harmonic_dictation").exists());
+        Ok(())
+    }
 }
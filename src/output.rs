@@ -0,0 +1,292 @@
+//! Utilities to manage the directories generated by this tool inside a Trane library. Courses are
+//! first built into a staging directory to compute the planned file tree, which is then compared
+//! against the existing output so that only the files that changed are written and stale files are
+//! removed.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use trane::{course_builder::CourseBuilder, data::CourseManifest};
+
+/// The metadata key added to every course generated by this tool, whatever the app it mirrors.
+pub const GENERATED_METADATA_KEY: &str = "generated_by";
+
+/// The value of the metadata key added to every course generated by this tool.
+pub const GENERATED_METADATA_VALUE: &str = "trane-earmaster";
+
+/// The metadata key that marked the generated courses when only EarMaster courses were generated.
+/// Courses generated back then are still recognized so that they can be updated or removed.
+const LEGACY_METADATA_KEY: &str = "earmaster";
+
+/// Returns whether the given directory contains a course generated by this tool. Only courses
+/// whose manifest contains the metadata added by this tool are considered generated, so that other
+/// courses in the same library are never touched.
+pub fn is_generated_course(directory: &Path) -> bool {
+    let manifest_path = directory.join("course_manifest.json");
+    let Ok(contents) = fs::read_to_string(manifest_path) else {
+        return false;
+    };
+    let Ok(manifest) = serde_json::from_str::<CourseManifest>(&contents) else {
+        return false;
+    };
+    let Some(metadata) = manifest.metadata else {
+        return false;
+    };
+    let has_value = |key: &str, expected: &str| {
+        metadata
+            .get(key)
+            .is_some_and(|values| values.iter().any(|value| value == expected))
+    };
+    has_value(GENERATED_METADATA_KEY, GENERATED_METADATA_VALUE)
+        || has_value(LEGACY_METADATA_KEY, "true")
+}
+
+/// Returns the course directories generated by this tool directly under the library root, sorted
+/// by path.
+pub fn generated_course_directories(library_root: &Path) -> Result<Vec<PathBuf>> {
+    if !library_root.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut directories = vec![];
+    for entry in fs::read_dir(library_root)
+        .with_context(|| format!("failed to read directory {}", library_root.display()))?
+    {
+        let path = entry?.path();
+        if path.is_dir() && is_generated_course(&path) {
+            directories.push(path);
+        }
+    }
+    directories.sort();
+    Ok(directories)
+}
+
+/// Removes every course directory generated by this tool under the library root and returns the
+/// removed directories.
+pub fn clean(library_root: &Path) -> Result<Vec<PathBuf>> {
+    let directories = generated_course_directories(library_root)?;
+    for directory in &directories {
+        fs::remove_dir_all(directory)
+            .with_context(|| format!("failed to remove directory {}", directory.display()))?;
+    }
+    Ok(directories)
+}
+
+/// The files that should exist in the library after building a set of courses.
+pub struct BuildPlan {
+    /// The names of the directories of the planned courses.
+    course_directories: BTreeSet<String>,
+
+    /// The contents of every planned file, indexed by its path relative to the library root.
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+/// Returns the paths of all the files under the given directory, relative to the root.
+fn read_files(root: &Path, directory: &Path, files: &mut BTreeMap<PathBuf, Vec<u8>>) -> Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_files(root, &path, files)?;
+        } else {
+            let relative_path = path.strip_prefix(root)?.to_path_buf();
+            files.insert(relative_path, fs::read(&path)?);
+        }
+    }
+    Ok(())
+}
+
+impl BuildPlan {
+    /// Computes the plan by building the given courses into a temporary directory.
+    pub fn new(course_builders: &[CourseBuilder]) -> Result<Self> {
+        let staging_dir = tempfile::TempDir::new()?;
+        let mut course_directories = BTreeSet::new();
+        for course_builder in course_builders {
+            course_builder.build(staging_dir.path())?;
+            course_directories.insert(course_builder.directory_name.clone());
+        }
+
+        let mut files = BTreeMap::new();
+        read_files(staging_dir.path(), staging_dir.path(), &mut files)?;
+        Ok(Self {
+            course_directories,
+            files,
+        })
+    }
+
+    /// Returns whether the given path, relative to the library root, is a planned file or a
+    /// directory containing planned files.
+    fn contains(&self, relative_path: &Path) -> bool {
+        self.files
+            .range(relative_path.to_path_buf()..)
+            .next()
+            .is_some_and(|(path, _)| path.starts_with(relative_path))
+    }
+}
+
+/// A change to a file or directory in the library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// A file that will be created.
+    Added(PathBuf),
+
+    /// A file whose contents will be replaced.
+    Changed(PathBuf),
+
+    /// A file or directory that will be removed because it is no longer part of the plan.
+    Removed(PathBuf),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(path) => write!(f, "added {}", path.display()),
+            Change::Changed(path) => write!(f, "changed {}", path.display()),
+            Change::Removed(path) => write!(f, "removed {}", path.display()),
+        }
+    }
+}
+
+/// Adds a removal for every file or directory inside the given directory that is not part of the
+/// plan. Directories without any planned file are removed as a whole.
+fn find_removals(
+    plan: &BuildPlan,
+    library_root: &Path,
+    directory: &Path,
+    changes: &mut Vec<Change>,
+) -> Result<()> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    paths.sort();
+    for path in paths {
+        let relative_path = path.strip_prefix(library_root)?.to_path_buf();
+        if path.is_dir() {
+            if plan.contains(&relative_path) {
+                find_removals(plan, library_root, &path, changes)?;
+            } else {
+                changes.push(Change::Removed(relative_path));
+            }
+        } else if !plan.files.contains_key(&relative_path) {
+            changes.push(Change::Removed(relative_path));
+        }
+    }
+    Ok(())
+}
+
+/// Compares the plan against the contents of the library and returns the changes needed to make
+/// them match. If `prune_courses` is true, generated courses that are not part of the plan are
+/// also removed.
+pub fn diff(plan: &BuildPlan, library_root: &Path, prune_courses: bool) -> Result<Vec<Change>> {
+    let mut changes = vec![];
+
+    // Verify that the planned courses do not overwrite a directory not generated by this tool.
+    for directory_name in &plan.course_directories {
+        let course_directory = library_root.join(directory_name);
+        if course_directory.exists() && !is_generated_course(&course_directory) {
+            bail!(
+                "directory {} already exists and was not generated by this tool",
+                course_directory.display()
+            );
+        }
+    }
+
+    for (relative_path, contents) in &plan.files {
+        let path = library_root.join(relative_path);
+        if !path.exists() {
+            changes.push(Change::Added(relative_path.clone()));
+        } else if path.is_dir() || fs::read(&path)? != *contents {
+            changes.push(Change::Changed(relative_path.clone()));
+        }
+    }
+
+    for directory in generated_course_directories(library_root)? {
+        let relative_path = directory.strip_prefix(library_root)?.to_path_buf();
+        if plan.contains(&relative_path) {
+            find_removals(plan, library_root, &directory, &mut changes)?;
+        } else if prune_courses {
+            changes.push(Change::Removed(relative_path));
+        }
+    }
+    Ok(changes)
+}
+
+/// Applies the changes computed from the plan to the library.
+pub fn apply(plan: &BuildPlan, changes: &[Change], library_root: &Path) -> Result<()> {
+    // Removals are applied first, so that a file can replace a directory with the same name.
+    for change in changes {
+        if let Change::Removed(relative_path) = change {
+            let path = library_root.join(relative_path);
+            if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            }
+            .with_context(|| format!("failed to remove {}", path.display()))?;
+        }
+    }
+
+    for change in changes {
+        if let Change::Added(relative_path) | Change::Changed(relative_path) = change {
+            let path = library_root.join(relative_path);
+            if path.is_dir() {
+                fs::remove_dir_all(&path)?;
+            }
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, &plan.files[relative_path])
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Change;
+    use crate::{build_courses, earmaster::catalog, BuildOptions};
+
+    /// Verifies that cleaning only removes the courses generated by this tool.
+    #[test]
+    fn clean_keeps_other_directories() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = temp_dir.path();
+        let courses = catalog::bundled_catalogs()?;
+        let options = BuildOptions {
+            only: vec!["scale_identification".to_string()],
+            ..Default::default()
+        };
+        build_courses(&courses, &options, library_root)?;
+        fs::create_dir(library_root.join("other"))?;
+
+        let removed = super::clean(library_root)?;
+        assert_eq!(removed, vec![library_root.join("scale_identification")]);
+        assert!(library_root.join("other").is_dir());
+        Ok(())
+    }
+
+    /// Verifies that rebuilding after removing a lesson from a catalog removes its directory.
+    #[test]
+    fn rebuild_prunes_stale_lessons() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = temp_dir.path();
+        let mut courses = catalog::bundled_catalogs()?;
+        courses.retain(|course| course.directory_name == "scale_identification");
+        build_courses(&courses, &BuildOptions::default(), library_root)?;
+        let lesson_directory = library_root.join("scale_identification/lesson_1.13");
+        assert!(lesson_directory.is_dir());
+
+        courses[0].lessons.retain(|lesson| lesson.id != "1.13");
+        let changes = build_courses(&courses, &BuildOptions::default(), library_root)?;
+        assert_eq!(
+            changes,
+            vec![Change::Removed(
+                lesson_directory.strip_prefix(library_root)?.to_path_buf()
+            )]
+        );
+        assert!(!lesson_directory.exists());
+        Ok(())
+    }
+}